@@ -3,15 +3,15 @@ pub struct DayX<'a> {
 }
 
 impl <'a> Day<'a> for DayX<'a> {
-  fn setup(input: &'a str) -> Self {
-    Self { input }
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    Ok(Self { input })
   }
 
-  fn part1(&mut self) -> String {
-    String::new()
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(String::new())
   }
 
-  fn part2(&mut self) -> String {
-    String::new()
+  fn part2(&mut self) -> anyhow::Result<String> {
+    Ok(String::new())
   }
 }
\ No newline at end of file
@@ -1,19 +0,0 @@
-use crate::Day;
-
-pub struct Day12<'a> {
-  input: &'a str
-}
-
-impl <'a> Day<'a> for Day12<'a> {
-  fn setup(input: &'a str) -> Self {
-    Self { input }
-  }
-
-  fn part1(&mut self) -> String {
-    String::new()
-  }
-
-  fn part2(&mut self) -> String {
-    String::new()
-  }
-}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use itertools::Itertools;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the inputs of every year when no other one is given,
+/// the `inputs` directory of the source tree the binary was built from
+pub fn default_root() -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")
+}
+
+/// Directory holding the inputs of a year
+fn year_dir(root: &Path, year: u32) -> PathBuf {
+  root.join(year.to_string())
+}
+
+/// Reads an input file, an empty file counts as a missing input
+fn read(path: &Path, what: &str) -> anyhow::Result<String> {
+  match std::fs::read_to_string(path) {
+    Ok(input) if !input.trim().is_empty() => Ok(input),
+    Ok(_) => Err(anyhow::anyhow!("{what} at {} is empty", path.display())),
+    Err(err) => Err(anyhow::anyhow!(
+      "Missing {}, expected it at {} ({err})",
+      what.to_lowercase(),
+      path.display()
+    )),
+  }
+}
+
+/// Where the default input for a day is expected to be
+pub fn input_path(root: &Path, year: u32, day: u32) -> PathBuf {
+  year_dir(root, year).join(format!("{day}.txt"))
+}
+
+/// Reads the default input for a day
+pub fn read_input(root: &Path, year: u32, day: u32) -> anyhow::Result<String> {
+  read(
+    &input_path(root, year, day),
+    &format!("Input for day {day}"),
+  )
+}
+
+/// Directory holding every profile of a day, as `<profile>.txt` with an optional `<profile>.answers`
+pub fn profile_dir(root: &Path, year: u32, day: u32) -> PathBuf {
+  year_dir(root, year).join(day.to_string())
+}
+
+/// Reads the input of a profile
+pub fn read_profile(root: &Path, year: u32, day: u32, profile: &str) -> anyhow::Result<String> {
+  let path = profile_dir(root, year, day).join(format!("{profile}.txt"));
+
+  read(&path, &format!("Input for day {day} of profile {profile}"))
+}
+
+/// Names of every profile of a day, sorted
+pub fn profiles(root: &Path, year: u32, day: u32) -> anyhow::Result<Vec<String>> {
+  let dir = profile_dir(root, year, day);
+  let entries = std::fs::read_dir(&dir).map_err(|err| {
+    anyhow::anyhow!(
+      "No profiles for day {day}, expected them in {} ({err})",
+      dir.display()
+    )
+  })?;
+
+  let mut profiles = vec![];
+
+  for entry in entries {
+    let path = entry?.path();
+
+    if path.extension().is_some_and(|ext| ext == "txt") {
+      if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+        profiles.push(name.to_string());
+      }
+    }
+  }
+
+  if profiles.is_empty() {
+    return Err(anyhow::anyhow!(
+      "No profiles for day {day} in {}",
+      dir.display()
+    ));
+  }
+
+  Ok(profiles.into_iter().sorted().collect_vec())
+}
+
+/// Recorded answers of a profile, one line per part where an empty line means unknown.
+///
+/// Returns an empty list if nothing has been recorded.
+pub fn read_answers(
+  root: &Path,
+  year: u32,
+  day: u32,
+  profile: &str,
+) -> anyhow::Result<Vec<Option<String>>> {
+  let path = profile_dir(root, year, day).join(format!("{profile}.answers"));
+
+  if !path.exists() {
+    return Ok(vec![]);
+  }
+
+  Ok(
+    std::fs::read_to_string(path)?
+      .lines()
+      .map(str::trim)
+      .map(|answer| (!answer.is_empty()).then(|| answer.to_string()))
+      .collect_vec(),
+  )
+}
@@ -0,0 +1,35 @@
+//! Iterator helpers std only has on nightly, built on stable unless the `nightly` feature is enabled
+
+use std::iter::Fuse;
+
+/// Extension methods for every [Iterator]
+pub trait IteratorExt: Iterator + Sized {
+  /// Groups the items into arrays of `N`, leftover items at the end are dropped,
+  /// see the nightly `Iterator::array_chunks`
+  fn arrays<const N: usize>(self) -> impl Iterator<Item = [Self::Item; N]> {
+    #[cfg(feature = "nightly")]
+    return self.array_chunks::<N>();
+
+    #[cfg(not(feature = "nightly"))]
+    return Arrays::<Self, N>(self.fuse());
+  }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Stable version of the nightly `std::iter::ArrayChunks`, see [IteratorExt::arrays]
+pub struct Arrays<I, const N: usize>(Fuse<I>);
+
+impl<I: Iterator, const N: usize> Iterator for Arrays<I, N> {
+  type Item = [I::Item; N];
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let items = [(); N].map(|_| self.0.next());
+
+    if items.iter().all(Option::is_some) {
+      Some(items.map(Option::unwrap))
+    } else {
+      None
+    }
+  }
+}
@@ -1,45 +1,25 @@
-#![feature(exact_size_is_empty)]
-#![feature(array_chunks)]
-#![feature(iter_collect_into)]
-#![feature(iter_array_chunks)]
-#![feature(slice_group_by)]
-#![feature(iter_map_windows)]
+#![cfg_attr(feature = "nightly", feature(iter_array_chunks))]
 #![allow(unused)]
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-
-use crate::day1::Day1;
-use crate::day10::Day10;
-use crate::day11::Day11;
-use crate::day12::Day12;
-use crate::day13::Day13;
-use crate::day2::Day2;
-use crate::day3::Day3;
-use crate::day4::Day4;
-use crate::day5::Day5;
-use crate::day6::Day6;
-use crate::day7::Day7;
-use crate::day8::Day8;
-use crate::day9::Day9;
+mod inputs;
+mod iter;
+mod parse;
+mod trace;
+mod y2023;
+
 use clap::Parser;
+use itertools::Itertools;
+use std::path::PathBuf;
 use std::time::Duration;
 
 /// Simple program to greet a person
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
+  /// Which year you want to run
+  #[arg(short, long, default_value_t = 2023)]
+  year: u32,
+
   /// Which day you want to run
   #[arg(short, long)]
   day: u32,
@@ -52,6 +32,19 @@ pub struct Args {
   #[arg(short, long)]
   input: Option<String>,
 
+  /// Directory to read inputs from, as <year>/<day>.txt, defaults to the inputs directory
+  /// of the source tree the binary was built from
+  #[arg(long, env = "AOC_INPUTS")]
+  inputs: Option<PathBuf>,
+
+  /// Use the input of this profile, from inputs/<year>/<day>/<profile>.txt
+  #[arg(long, conflicts_with_all = ["input", "bench"])]
+  profile: Option<String>,
+
+  /// Run every profile of the day and check each against its recorded answers
+  #[arg(long, conflicts_with_all = ["input", "profile", "bench"])]
+  all_profiles: bool,
+
   /// Benchmark
   #[arg(short, long)]
   bench: bool,
@@ -59,54 +52,97 @@ pub struct Args {
   /// Benchmark time in ms
   #[arg(short, long, default_value_t = 2000)]
   time: u64,
+
+  /// Log diagnostics to stderr, -vv for more detail, ignored when benchmarking
+  #[arg(short, long, action = clap::ArgAction::Count)]
+  verbose: u8,
+
+  /// Day 1: spelled out digits for part 2, a language (en, de, fr, es, nl)
+  /// or ten comma separated words from zero to nine
+  #[arg(long)]
+  words: Option<String>,
+
+  /// Day 2: cubes in the bag for part 1, like "12 red, 13 green, 14 blue"
+  #[arg(long)]
+  bag: Option<String>,
+
+  /// Day 4: explain how many copies of every card were won and where they came from
+  #[arg(long)]
+  explain: bool,
+
+  /// Day 8: write the network as a Graphviz DOT file to this path
+  #[arg(long)]
+  dot: Option<String>,
+
+  /// Day 8: annotate the path of each ghost in the DOT file
+  #[arg(long, requires = "dot")]
+  dot_ghosts: bool,
+
+  /// Day 9: predict every history this many steps from its first value, so 0 is the first value,
+  /// the length of a history is the value after its last one and -1 the value before its first
+  #[arg(long, allow_negative_numbers = true)]
+  offset: Option<i128>,
+
+  /// Day 11: sum the distances between galaxies with this expansion factor
+  #[arg(long)]
+  factor: Option<std::num::NonZeroU64>,
+
+  /// Day 13: report the reflection of every pattern with this many smudges
+  #[arg(long)]
+  smudges: Option<u32>,
+
+  /// Print a visual map of the puzzle, for days that support it (3, 10)
+  #[arg(long)]
+  render: bool,
 }
 
-pub trait Day<'a> {
-  fn setup(input: &'a str) -> Self;
+impl Args {
+  /// Directory to read inputs from, see [Args::inputs]
+  fn inputs_root(&self) -> PathBuf {
+    self.inputs.clone().unwrap_or_else(inputs::default_root)
+  }
+}
+
+pub trait Day<'a>: Sized {
+  fn setup(input: &'a str) -> anyhow::Result<Self>;
+
+  /// Applies any day specific options, called after [Day::setup] when not benchmarking
+  fn configure(&mut self, _args: &Args) -> anyhow::Result<()> {
+    Ok(())
+  }
 
-  fn part1(&mut self) -> String;
-  fn part2(&mut self) -> String;
+  fn part1(&mut self) -> anyhow::Result<String>;
+  fn part2(&mut self) -> anyhow::Result<String>;
 }
 
-pub const DEFAULT_INPUTS: &[&str] = &[
-  "", // Day 0, just so I don't have to do -1 when indexing
-  include_str!("../inputs/1.txt"),
-  include_str!("../inputs/2.txt"),
-  include_str!("../inputs/3.txt"),
-  include_str!("../inputs/4.txt"),
-  include_str!("../inputs/5.txt"),
-  include_str!("../inputs/6.txt"),
-  include_str!("../inputs/7.txt"),
-  include_str!("../inputs/8.txt"),
-  include_str!("../inputs/9.txt"),
-  include_str!("../inputs/10.txt"),
-  include_str!("../inputs/11.txt"),
-  include_str!("../inputs/12.txt"),
-  include_str!("../inputs/13.txt"),
-  // include_str!("../inputs/14.txt"),
-  // include_str!("../inputs/15.txt"),
-  // include_str!("../inputs/16.txt"),
-  // include_str!("../inputs/17.txt"),
-  // include_str!("../inputs/18.txt"),
-  // include_str!("../inputs/19.txt"),
-  // include_str!("../inputs/20.txt"),
-  // include_str!("../inputs/21.txt"),
-  // include_str!("../inputs/22.txt"),
-  // include_str!("../inputs/23.txt"),
-  // include_str!("../inputs/24.txt"),
-  // include_str!("../inputs/25.txt"),
-];
+/// Number of days in an Advent of Code event
+pub const DAYS: u32 = 25;
+
+/// Answers of every part that ran, as `(part, answer)`
+pub type Answers = Vec<(u32, String)>;
+
+/// Runs a day with the given input, see [y2023::solver]
+pub type Solver = fn(&str, &Args) -> anyhow::Result<Answers>;
+
+/// Finds the solver for a day of a year, see [y2023::solver]
+pub type SolverLookup = fn(u32) -> Option<Solver>;
+
+/// Every supported year, along with its solver lookup
+pub const YEARS: &[(u32, SolverLookup)] = &[(2023, y2023::solver)];
 
 pub fn run_benchmark<'a, D: Day<'a>>(input: &'a str, args: &Args) -> anyhow::Result<()> {
+  // Make sure the input is valid before benchmarking, so errors don't end up in the measurements
+  D::setup(input)?;
+
   match args.part {
     0 => {
       let result =
         benchmarking::bench_function_with_duration(Duration::from_millis(args.time), |m| {
           m.measure(|| {
-            let mut d = D::setup(input);
+            let mut d = D::setup(input).unwrap();
 
-            d.part1();
-            d.part2()
+            d.part1().unwrap();
+            d.part2().unwrap()
           });
         })?;
 
@@ -120,7 +156,7 @@ pub fn run_benchmark<'a, D: Day<'a>>(input: &'a str, args: &Args) -> anyhow::Res
       let result =
         benchmarking::bench_function_with_duration(Duration::from_millis(args.time), |m| {
           m.measure(|| {
-            D::setup(input).part1();
+            D::setup(input).unwrap().part1().unwrap();
           });
         })?;
 
@@ -134,7 +170,7 @@ pub fn run_benchmark<'a, D: Day<'a>>(input: &'a str, args: &Args) -> anyhow::Res
       let result =
         benchmarking::bench_function_with_duration(Duration::from_millis(args.time), |m| {
           m.measure(|| {
-            D::setup(input).part2();
+            D::setup(input).unwrap().part2().unwrap();
           });
         })?;
 
@@ -150,63 +186,111 @@ pub fn run_benchmark<'a, D: Day<'a>>(input: &'a str, args: &Args) -> anyhow::Res
   Ok(())
 }
 
-pub fn run_day<'a, D: Day<'a>>(input: &'a str, args: &Args) -> anyhow::Result<()> {
+pub fn run_day<'a, D: Day<'a>>(input: &'a str, args: &Args) -> anyhow::Result<Answers> {
   if args.bench {
-    return run_benchmark::<D>(input, args);
+    run_benchmark::<D>(input, args)?;
+
+    return Ok(vec![]);
   }
 
-  let mut day = D::setup(input);
+  let mut day = D::setup(input)?;
+  day.configure(args)?;
 
   match args.part {
-    0 => {
-      println!("Day {} Part 1: {}", args.day, day.part1());
-      println!("Day {} Part 2: {}", args.day, day.part2());
-    }
-    1 => {
-      println!("Day {} Part 1: {}", args.day, day.part1());
-    }
-    2 => {
-      println!("Day {} Part 2: {}", args.day, day.part2());
-    }
-    _ => return Err(anyhow::Error::msg("Invalid Part")),
+    0 => Ok(vec![(1, day.part1()?), (2, day.part2()?)]),
+    1 => Ok(vec![(1, day.part1()?)]),
+    2 => Ok(vec![(2, day.part2()?)]),
+    _ => Err(anyhow::Error::msg("Invalid Part")),
   }
+}
 
-  Ok(())
+/// Runs a day with the input of a profile and checks the answers against the recorded ones,
+/// returns whether none of them were wrong
+pub fn run_profile(solver: Solver, profile: &str, args: &Args) -> anyhow::Result<bool> {
+  let root = args.inputs_root();
+  let input = inputs::read_profile(&root, args.year, args.day, profile)?;
+  let recorded = inputs::read_answers(&root, args.year, args.day, profile)?;
+  let mut correct = true;
+
+  for (part, answer) in solver(&input, args)? {
+    let status = match recorded.get(part as usize - 1) {
+      Some(Some(expected)) if *expected == answer => String::from("correct"),
+      Some(Some(expected)) => {
+        correct = false;
+        format!("wrong, expected {expected}")
+      }
+      _ => String::from("no recorded answer"),
+    };
+
+    println!(
+      "Day {} Part {part} [{profile}]: {answer} ({status})",
+      args.day
+    );
+  }
+
+  Ok(correct)
 }
 
 pub fn main() -> anyhow::Result<()> {
   let args = Args::try_parse()?;
-  let input = args
-    .input
-    .as_deref()
-    .unwrap_or_else(|| DEFAULT_INPUTS[args.day as usize]);
-
-  match args.day {
-    1 => run_day::<Day1>(input, &args),
-    2 => run_day::<Day2>(input, &args),
-    3 => run_day::<Day3>(input, &args),
-    4 => run_day::<Day4>(input, &args),
-    5 => run_day::<Day5>(input, &args),
-    6 => run_day::<Day6>(input, &args),
-    7 => run_day::<Day7>(input, &args),
-    8 => run_day::<Day8>(input, &args),
-    9 => run_day::<Day9>(input, &args),
-    10 => run_day::<Day10>(input, &args),
-    11 => run_day::<Day11>(input, &args),
-    12 => run_day::<Day12>(input, &args),
-    13 => run_day::<Day13>(input, &args),
-    // 14 => run_day::<Day14>(input, &args),
-    // 15 => run_day::<Day15>(input, &args),
-    // 16 => run_day::<Day16>(input, &args),
-    // 17 => run_day::<Day17>(input, &args),
-    // 18 => run_day::<Day18>(input, &args),
-    // 19 => run_day::<Day19>(input, &args),
-    // 20 => run_day::<Day20>(input, &args),
-    // 21 => run_day::<Day21>(input, &args),
-    // 22 => run_day::<Day22>(input, &args),
-    // 23 => run_day::<Day23>(input, &args),
-    // 24 => run_day::<Day24>(input, &args),
-    // 25 => run_day::<Day25>(input, &args),
-    _ => Err(anyhow::Error::msg("Invalid Day")),
+
+  trace::set_verbosity(if args.bench { 0 } else { args.verbose });
+
+  if !(1..=DAYS).contains(&args.day) {
+    return Err(anyhow::Error::msg("Invalid Day"));
   }
+
+  let Some(&(_, solver)) = YEARS.iter().find(|(year, _)| *year == args.year) else {
+    return Err(anyhow::anyhow!(
+      "Year {} is not supported, available years: {}",
+      args.year,
+      YEARS.iter().map(|(year, _)| year).join(", ")
+    ));
+  };
+
+  let Some(solver) = solver(args.day) else {
+    return Err(anyhow::anyhow!(
+      "Day {} of {} is not implemented",
+      args.day,
+      args.year
+    ));
+  };
+
+  if args.all_profiles {
+    let mut wrong = vec![];
+
+    for profile in inputs::profiles(&args.inputs_root(), args.year, args.day)? {
+      if !run_profile(solver, &profile, &args)? {
+        wrong.push(profile);
+      }
+    }
+
+    if !wrong.is_empty() {
+      return Err(anyhow::anyhow!(
+        "Wrong answers for profiles: {}",
+        wrong.join(", ")
+      ));
+    }
+
+    return Ok(());
+  }
+
+  if let Some(profile) = &args.profile {
+    if !run_profile(solver, profile, &args)? {
+      return Err(anyhow::anyhow!("Wrong answers for profile {profile}"));
+    }
+
+    return Ok(());
+  }
+
+  let input = match &args.input {
+    Some(input) => input.clone(),
+    None => inputs::read_input(&args.inputs_root(), args.year, args.day)?,
+  };
+
+  for (part, answer) in solver(&input, &args)? {
+    println!("Day {} Part {part}: {answer}", args.day);
+  }
+
+  Ok(())
 }
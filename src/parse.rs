@@ -0,0 +1,236 @@
+//! Helpers for parsing puzzle inputs, where every error points at the line and column it came from
+
+use itertools::Itertools;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A line of the input, along with where it is so errors can point at it
+#[derive(Debug, Copy, Clone)]
+pub struct Line<'a> {
+  /// 0-based line index
+  pub index: usize,
+  pub text: &'a str,
+}
+
+/// Every line of the input
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+  input
+    .lines()
+    .enumerate()
+    .map(|(index, text)| Line { index, text })
+}
+
+/// Every line of the input as bytes, for hot paths that don't want to deal with `char`s,
+/// handles `\r\n` like [str::lines]
+pub fn byte_lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+  input
+    .strip_suffix(b"\n")
+    .unwrap_or(input)
+    .split(|&b| b == b'\n')
+    .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+    .filter(|_| !input.is_empty())
+}
+
+/// An error on a line from [byte_lines], see [Line::error]
+pub fn byte_line_error(index: usize, line: &[u8], message: impl Display) -> anyhow::Error {
+  anyhow::anyhow!(
+    "Line {}: {message}\n  {}",
+    index + 1,
+    String::from_utf8_lossy(line)
+  )
+}
+
+/// Every whitespace separated integer of a line from [byte_lines], without allocating per number
+pub fn byte_integers(index: usize, line: &[u8]) -> anyhow::Result<Vec<i64>> {
+  line
+    .split(u8::is_ascii_whitespace)
+    .filter(|word| !word.is_empty())
+    .map(|word| {
+      let (negative, digits) = match word.strip_prefix(b"-") {
+        Some(digits) => (true, digits),
+        None => (false, word),
+      };
+
+      let error = || {
+        let word = String::from_utf8_lossy(word);
+        byte_line_error(index, line, format!("can't parse {word:?} as an integer"))
+      };
+
+      if digits.is_empty() {
+        return Err(error());
+      }
+
+      let mut n = 0i64;
+
+      for &digit in digits {
+        if !digit.is_ascii_digit() {
+          return Err(error());
+        }
+
+        let digit = (digit - b'0') as i64;
+        n = n
+          .checked_mul(10)
+          .and_then(|n| match negative {
+            true => n.checked_sub(digit),
+            false => n.checked_add(digit),
+          })
+          .ok_or_else(error)?;
+      }
+
+      Ok(n)
+    })
+    .collect()
+}
+
+/// Blank-line-separated blocks of lines, empty blocks are skipped
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+  lines(input)
+    .collect_vec()
+    .split(|line| line.text.trim().is_empty())
+    .filter(|block| !block.is_empty())
+    .map(Vec::from)
+    .collect_vec()
+}
+
+/// A rectangular grid where every character is turned into a cell by `cell`,
+/// errors on characters `cell` doesn't know, on empty lines and on lines of a different width
+pub fn grid<T>(input: &str, cell: impl Fn(char) -> Option<T>) -> anyhow::Result<Vec<Vec<T>>> {
+  let mut grid = Vec::new();
+  let mut width = None;
+
+  for line in lines(input) {
+    let row = line
+      .text
+      .char_indices()
+      .map(|(column, c)| {
+        cell(c).ok_or_else(|| line.error(column, format!("unexpected character {c:?}")))
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if row.is_empty() {
+      return Err(line.error(0, "expected a row of the grid, got an empty line"));
+    }
+
+    match width {
+      None => width = Some(row.len()),
+      Some(width) if width != row.len() => {
+        return Err(line.error(
+          0,
+          format!(
+            "expected {width} cells like the first line, got {}",
+            row.len()
+          ),
+        ))
+      }
+      _ => (),
+    }
+
+    grid.push(row);
+  }
+
+  if grid.is_empty() {
+    return Err(anyhow::Error::msg(
+      "Expected a grid, but the input is empty",
+    ));
+  }
+
+  Ok(grid)
+}
+
+impl<'a> Line<'a> {
+  /// An error at a 0-based byte column of this line
+  pub fn error(&self, column: usize, message: impl Display) -> anyhow::Error {
+    anyhow::anyhow!(
+      "Line {}, column {}: {message}\n  {}",
+      self.index + 1,
+      column + 1,
+      self.text
+    )
+  }
+
+  /// 0-based byte column where `part` starts, `part` has to be a slice of this line
+  pub fn column_of(&self, part: &str) -> usize {
+    (part.as_ptr() as usize).saturating_sub(self.text.as_ptr() as usize)
+  }
+
+  /// An error pointing at `part`, which has to be a slice of this line
+  pub fn error_at(&self, part: &str, message: impl Display) -> anyhow::Error {
+    self.error(self.column_of(part), message)
+  }
+
+  /// Parses `part` of this line
+  pub fn parse<T>(&self, part: &'a str) -> anyhow::Result<T>
+  where
+    T: FromStr,
+    T::Err: Display,
+  {
+    part
+      .trim()
+      .parse()
+      .map_err(|err| self.error_at(part.trim(), format!("can't parse {part:?}: {err}")))
+  }
+
+  /// Splits `part` of this line at the first `delimiter`
+  pub fn split_once(&self, part: &'a str, delimiter: &str) -> anyhow::Result<(&'a str, &'a str)> {
+    part
+      .split_once(delimiter)
+      .ok_or_else(|| self.error_at(part, format!("expected {delimiter:?}")))
+  }
+
+  /// Splits a `label: value` line, both sides are trimmed
+  pub fn labelled(&self) -> anyhow::Result<(&'a str, &'a str)> {
+    let (label, value) = self.split_once(self.text, ":")?;
+
+    Ok((label.trim(), value.trim()))
+  }
+
+  /// Splits a `label: value` line, erroring if the label isn't `expected`
+  pub fn expect_label(&self, expected: &str) -> anyhow::Result<&'a str> {
+    let (label, value) = self.labelled()?;
+
+    if label != expected {
+      return Err(self.error_at(label, format!("expected {expected:?}, got {label:?}")));
+    }
+
+    Ok(value)
+  }
+
+  /// Every integer in this line, see [Line::integers_in]
+  pub fn integers<T>(&self) -> anyhow::Result<Vec<T>>
+  where
+    T: FromStr,
+    T::Err: Display,
+  {
+    self.integers_in(self.text)
+  }
+
+  /// Every integer in `part` of this line, ignoring anything that isn't a digit,
+  /// a `-` directly in front of digits makes it negative
+  pub fn integers_in<T>(&self, part: &'a str) -> anyhow::Result<Vec<T>>
+  where
+    T: FromStr,
+    T::Err: Display,
+  {
+    let bytes = part.as_bytes();
+    let mut integers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+      if !bytes[i].is_ascii_digit() {
+        i += 1;
+        continue;
+      }
+
+      let negative = i > 0 && bytes[i - 1] == b'-';
+      let start = if negative { i - 1 } else { i };
+
+      while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+      }
+
+      integers.push(self.parse(&part[start..i])?);
+    }
+
+    Ok(integers)
+  }
+}
@@ -0,0 +1,37 @@
+//! Diagnostics solvers can log through, printed to stderr with `-v` or `-vv` so they never end up
+//! in the answers. Logging is turned off while benchmarking, where the macros don't format anything.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 0 logs nothing, 1 logs [debug!] and 2 also logs [trace!]
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_verbosity(verbosity: u8) {
+  VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+/// Whether messages of this verbosity get logged
+#[inline]
+pub fn enabled(verbosity: u8) -> bool {
+  VERBOSITY.load(Ordering::Relaxed) >= verbosity
+}
+
+/// Logs a message with `-v`, takes the same arguments as [format!]
+#[macro_export]
+macro_rules! debug {
+  ($($arg:tt)*) => {
+    if $crate::trace::enabled(1) {
+      eprintln!($($arg)*);
+    }
+  };
+}
+
+/// Logs a message with `-vv`, for details that are too noisy for [debug!]
+#[macro_export]
+macro_rules! trace {
+  ($($arg:tt)*) => {
+    if $crate::trace::enabled(2) {
+      eprintln!($($arg)*);
+    }
+  };
+}
@@ -0,0 +1,183 @@
+use crate::parse;
+use crate::{Args, Day};
+use itertools::Itertools;
+
+pub struct Day1<'a> {
+  input: &'a [u8],
+  /// Spelled out digits from zero to nine, [NAMED] unless configured with `--words`
+  words: Option<Vec<String>>,
+}
+
+const EXAMPLE: &str = r#"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet"#;
+
+const EXAMPLE2: &str = r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"#;
+
+const NAMED: [&str; 10] = [
+  "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Spelled out digits of every built in language, usable with `--words`
+const LANGUAGES: [(&str, [&str; 10]); 5] = [
+  ("en", NAMED),
+  (
+    "de",
+    [
+      "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+    ],
+  ),
+  (
+    "fr",
+    [
+      "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+    ],
+  ),
+  (
+    "es",
+    [
+      "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+    ],
+  ),
+  (
+    "nl",
+    [
+      "nul", "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
+    ],
+  ),
+];
+
+/// Parses a word table, either the name of one of the [LANGUAGES] or ten comma separated words
+fn parse_words(words: &str) -> anyhow::Result<Vec<String>> {
+  if let Some((_, words)) = LANGUAGES.iter().find(|(language, _)| *language == words) {
+    return Ok(words.iter().map(|word| word.to_string()).collect_vec());
+  }
+
+  let words = words
+    .split(',')
+    .map(|word| word.trim().to_string())
+    .collect_vec();
+
+  if words.len() != 10 || words.iter().any(|word| word.is_empty()) {
+    return Err(anyhow::anyhow!(
+      "Expected a language ({}) or 10 comma separated words from zero to nine, got {:?}",
+      LANGUAGES.iter().map(|(language, _)| language).join(", "),
+      words.join(",")
+    ));
+  }
+
+  Ok(words)
+}
+
+/// Value of a digit starting at `index`, either written out or as one of `names`,
+/// where the longest name wins if several match
+fn digit_at<W: AsRef<[u8]>>(line: &[u8], index: usize, names: &[W]) -> Option<u32> {
+  if line[index].is_ascii_digit() {
+    return Some((line[index] - b'0') as u32);
+  }
+
+  names
+    .iter()
+    .enumerate()
+    .filter(|(_, name)| line[index..].starts_with(name.as_ref()))
+    .max_by_key(|(_, name)| name.as_ref().len())
+    .map(|(n, _)| n as u32)
+}
+
+/// Sum of the first and last digit of every line, without allocating.
+///
+/// The first digit is searched from the start and the last from the end, each on its own,
+/// so overlapping words like `eightwo` count as both 8 and 2.
+fn calibrate<W: AsRef<[u8]>>(input: &[u8], names: &[W]) -> anyhow::Result<u32> {
+  parse::byte_lines(input)
+    .enumerate()
+    .map(|(index, line)| {
+      let mut digits = (0..line.len()).filter_map(|i| digit_at(line, i, names));
+      let first = digits
+        .next()
+        .ok_or_else(|| parse::byte_line_error(index, line, "no digits"))?;
+      let last = digits.next_back().unwrap_or(first);
+
+      Ok(first * 10 + last)
+    })
+    .sum()
+}
+
+impl<'a> Day<'a> for Day1<'a> {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    Ok(Day1 {
+      input: input.as_bytes(),
+      words: None,
+    })
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    if let Some(words) = &args.words {
+      self.words = Some(parse_words(words)?);
+    }
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(calibrate::<&str>(self.input, &[])?.to_string())
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    let sum = match &self.words {
+      Some(words) => calibrate(self.input, words)?,
+      None => calibrate(self.input, &NAMED)?,
+    };
+
+    Ok(sum.to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use clap::Parser;
+
+  #[test]
+  fn examples() {
+    assert_eq!(Day1::setup(EXAMPLE).unwrap().part1().unwrap(), "142");
+    assert_eq!(Day1::setup(EXAMPLE2).unwrap().part2().unwrap(), "281");
+  }
+
+  #[test]
+  fn overlapping_words() {
+    assert_eq!(calibrate(b"eightwo", &NAMED).unwrap(), 82);
+    assert_eq!(calibrate(b"oneight", &NAMED).unwrap(), 18);
+    assert_eq!(calibrate(b"twone", &NAMED).unwrap(), 21);
+    assert_eq!(
+      calibrate(b"xtwone3four\nzoneight", &NAMED).unwrap(),
+      24 + 18
+    );
+  }
+
+  #[test]
+  fn single_digit() {
+    assert_eq!(calibrate::<&str>(b"treb7uchet", &[]).unwrap(), 77);
+    assert_eq!(calibrate(b"sixteen", &NAMED).unwrap(), 66);
+    assert!(calibrate::<&str>(b"abc", &[]).is_err());
+  }
+
+  #[test]
+  fn custom_words() {
+    let args = Args::parse_from(["aoc", "-d", "1", "--words", "de"]);
+    let mut day = Day1::setup("dreiundzwei\nacht").unwrap();
+    day.configure(&args).unwrap();
+    assert_eq!(day.part2().unwrap(), (32 + 88).to_string());
+
+    let words = parse_words("o,i,ii,iii,iv,v,vi,vii,viii,ix").unwrap();
+    assert_eq!(calibrate(b"xviiix", &words).unwrap(), 89);
+    assert!(parse_words("one,two").is_err());
+  }
+}
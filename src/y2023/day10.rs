@@ -0,0 +1,325 @@
+use crate::parse;
+use crate::{Args, Day};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter, Write};
+
+pub struct Day10 {
+  grid: Vec<Vec<Tile>>,
+  start: (usize, usize),
+  main_loop: Vec<(usize, usize)>,
+}
+
+const EXAMPLE: &str = r#"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."#;
+
+const EXAMPLE2: &str = r#"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"#;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Direction {
+  North,
+  East,
+  South,
+  West,
+}
+
+impl Direction {
+  const ALL: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+  fn opposite(self) -> Self {
+    match self {
+      Self::North => Self::South,
+      Self::East => Self::West,
+      Self::South => Self::North,
+      Self::West => Self::East,
+    }
+  }
+
+  fn step(self, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+    match self {
+      Self::North => Some((x, y.checked_sub(1)?)),
+      Self::East => Some((x + 1, y)),
+      Self::South => Some((x, y + 1)),
+      Self::West => Some((x.checked_sub(1)?, y)),
+    }
+  }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Tile {
+  Vertical,
+  Horizontal,
+  NorthEast,
+  NorthWest,
+  SouthWest,
+  SouthEast,
+  Ground,
+  Start,
+}
+
+impl Tile {
+  const PIPES: [Tile; 6] = [
+    Self::Vertical,
+    Self::Horizontal,
+    Self::NorthEast,
+    Self::NorthWest,
+    Self::SouthWest,
+    Self::SouthEast,
+  ];
+
+  fn new(c: char) -> Option<Self> {
+    match c {
+      '|' => Some(Self::Vertical),
+      '-' => Some(Self::Horizontal),
+      'L' => Some(Self::NorthEast),
+      'J' => Some(Self::NorthWest),
+      '7' => Some(Self::SouthWest),
+      'F' => Some(Self::SouthEast),
+      '.' => Some(Self::Ground),
+      'S' => Some(Self::Start),
+      _ => None,
+    }
+  }
+
+  fn connections(self) -> Option<[Direction; 2]> {
+    use Direction::*;
+
+    match self {
+      Self::Vertical => Some([North, South]),
+      Self::Horizontal => Some([East, West]),
+      Self::NorthEast => Some([North, East]),
+      Self::NorthWest => Some([North, West]),
+      Self::SouthWest => Some([South, West]),
+      Self::SouthEast => Some([South, East]),
+      Self::Ground | Self::Start => None,
+    }
+  }
+
+  fn connects(self, direction: Direction) -> bool {
+    self
+      .connections()
+      .is_some_and(|connections| connections.contains(&direction))
+  }
+}
+
+impl Display for Tile {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_char(match self {
+      Tile::Vertical => '│',
+      Tile::Horizontal => '─',
+      Tile::NorthEast => '└',
+      Tile::NorthWest => '┘',
+      Tile::SouthWest => '┐',
+      Tile::SouthEast => '┌',
+      Tile::Ground => '.',
+      Tile::Start => 'S',
+    })
+  }
+}
+
+impl Day10 {
+  fn get(&self, (x, y): (usize, usize)) -> Option<Tile> {
+    self.grid.get(y)?.get(x).copied()
+  }
+
+  /// Follows the pipes from the start until getting back to it
+  fn find_loop(&self) -> anyhow::Result<Vec<(usize, usize)>> {
+    let start_tile = self.get(self.start).unwrap();
+    let [mut direction, _] = start_tile.connections().unwrap();
+    let mut current = self.start;
+    let mut main_loop = vec![];
+
+    loop {
+      main_loop.push(current);
+
+      let next = direction
+        .step(current)
+        .filter(|&next| {
+          self
+            .get(next)
+            .is_some_and(|tile| tile.connects(direction.opposite()))
+        })
+        .ok_or_else(|| anyhow::anyhow!("Loop is broken at {current:?} going {direction:?}"))?;
+
+      if next == self.start {
+        return Ok(main_loop);
+      }
+
+      let tile = self.get(next).unwrap();
+      let [a, b] = tile.connections().unwrap();
+
+      direction = if a == direction.opposite() { b } else { a };
+      current = next;
+    }
+  }
+
+  /// Every tile enclosed by the main loop, found by scanning each row and flipping
+  /// between outside and inside whenever a loop tile connecting north is crossed
+  fn enclosed(&self) -> Vec<(usize, usize)> {
+    let mut on_loop = vec![vec![false; self.grid[0].len()]; self.grid.len()];
+
+    for &(x, y) in &self.main_loop {
+      on_loop[y][x] = true;
+    }
+
+    let mut enclosed = vec![];
+
+    for (y, line) in self.grid.iter().enumerate() {
+      let mut inside = false;
+
+      for (x, tile) in line.iter().enumerate() {
+        if on_loop[y][x] {
+          if tile.connects(Direction::North) {
+            inside = !inside;
+          }
+        } else if inside {
+          enclosed.push((x, y));
+        }
+      }
+    }
+
+    enclosed
+  }
+
+  /// Draws the main loop, with enclosed tiles marked as `I` and everything else as `.`
+  fn render(&self) -> String {
+    let mut map = vec![vec![String::from("."); self.grid[0].len()]; self.grid.len()];
+
+    for &(x, y) in &self.main_loop {
+      map[y][x] = self.grid[y][x].to_string();
+    }
+
+    for (x, y) in self.enclosed() {
+      map[y][x] = String::from("I");
+    }
+
+    let (x, y) = self.start;
+    map[y][x] = Tile::Start.to_string();
+
+    map.iter().map(|line| line.concat()).join("\n")
+  }
+}
+
+impl<'a> Day<'a> for Day10 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let grid = parse::grid(input, Tile::new)?;
+
+    let starts = grid
+      .iter()
+      .enumerate()
+      .flat_map(|(y, line)| {
+        line
+          .iter()
+          .positions(|&tile| tile == Tile::Start)
+          .map(move |x| (x, y))
+      })
+      .collect_vec();
+
+    let &[start] = starts.as_slice() else {
+      return Err(anyhow::anyhow!(
+        "Expected exactly one start, found {}",
+        starts.len()
+      ));
+    };
+
+    // The start connects to every neighbour that connects back to it
+    let connected = Direction::ALL
+      .into_iter()
+      .filter(|direction| {
+        direction
+          .step(start)
+          .and_then(|(x, y)| grid.get(y)?.get(x))
+          .is_some_and(|tile| tile.connects(direction.opposite()))
+      })
+      .collect_vec();
+
+    let mut day = Self {
+      grid,
+      start,
+      main_loop: vec![],
+    };
+
+    // A neighbour can point at the start without being on the loop, so try every pipe
+    // joining two of them until one closes the loop
+    for tile in Tile::PIPES {
+      let [a, b] = tile.connections().unwrap();
+
+      if !connected.contains(&a) || !connected.contains(&b) {
+        continue;
+      }
+
+      day.grid[start.1][start.0] = tile;
+
+      if let Ok(main_loop) = day.find_loop() {
+        day.main_loop = main_loop;
+        return Ok(day);
+      }
+    }
+
+    Err(anyhow::anyhow!(
+      "Can't infer the tile under the start, no pipe through it closes a loop, it connects {connected:?}"
+    ))
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    if args.render {
+      println!("{}", self.render());
+    }
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok((self.main_loop.len() / 2).to_string())
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    // Shoelace formula for the area of the loop
+    let twice_area = self
+      .main_loop
+      .iter()
+      .circular_tuple_windows()
+      .map(|(&(x1, y1), &(x2, y2))| (x1 * y2) as i64 - (x2 * y1) as i64)
+      .sum::<i64>()
+      .abs();
+
+    // Pick's theorem, A = i + b/2 - 1, solved for the interior points
+    let boundary = self.main_loop.len() as i64;
+    let interior = (twice_area - boundary) / 2 + 1;
+
+    Ok(interior.to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn examples() {
+    let mut day = Day10::setup(EXAMPLE).unwrap();
+    assert_eq!(day.part1().unwrap(), "8");
+    assert_eq!(day.part2().unwrap(), "1");
+
+    let mut day = Day10::setup(EXAMPLE2).unwrap();
+    assert_eq!(day.part1().unwrap(), "80");
+    assert_eq!(day.part2().unwrap(), "10");
+  }
+
+  #[test]
+  fn start_next_to_pipe_off_the_loop() {
+    let mut day = Day10::setup(".|...\n.S-7.\n.|.|.\n.L-J.").unwrap();
+    assert_eq!(day.part1().unwrap(), "4");
+  }
+}
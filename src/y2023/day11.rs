@@ -0,0 +1,143 @@
+use crate::parse;
+use crate::{Args, Day};
+use itertools::Itertools;
+use std::num::NonZeroU64;
+
+pub struct Day11 {
+  galaxies: Vec<(usize, usize)>,
+  /// Number of empty columns before each column
+  empty_columns: Vec<usize>,
+  /// Number of empty rows before each row
+  empty_rows: Vec<usize>,
+}
+
+const EXAMPLE: &str = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+
+/// Turns a list of which lines are empty into how many empty lines come before each line
+fn prefix_sum(empty: impl Iterator<Item = bool>) -> Vec<usize> {
+  empty
+    .scan(0, |count, empty| {
+      let before = *count;
+      *count += empty as usize;
+      Some(before)
+    })
+    .collect_vec()
+}
+
+/// Sum of the distance between every pair of `coords`, by sorting them and counting how much
+/// each coordinate contributes against all the ones before it, instead of visiting every pair
+fn sum_pairwise(mut coords: Vec<u128>) -> u128 {
+  coords.sort_unstable();
+
+  let mut sum = 0;
+  let mut prefix = 0;
+
+  for (i, &n) in coords.iter().enumerate() {
+    sum += n * i as u128 - prefix;
+    prefix += n;
+  }
+
+  sum
+}
+
+impl Day11 {
+  /// Sum of the shortest paths between every pair of galaxies,
+  /// where each empty row and column is `factor` times as large
+  pub fn distance_sum(&self, factor: NonZeroU64) -> u128 {
+    let expand =
+      |n: usize, empty_before: usize| n as u128 + empty_before as u128 * (factor.get() as u128 - 1);
+
+    let xs = self
+      .galaxies
+      .iter()
+      .map(|&(x, _)| expand(x, self.empty_columns[x]))
+      .collect_vec();
+
+    let ys = self
+      .galaxies
+      .iter()
+      .map(|&(_, y)| expand(y, self.empty_rows[y]))
+      .collect_vec();
+
+    sum_pairwise(xs) + sum_pairwise(ys)
+  }
+}
+
+impl<'a> Day<'a> for Day11 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let grid = parse::grid(input, |c| match c {
+      '#' => Some(true),
+      '.' => Some(false),
+      _ => None,
+    })?;
+
+    let mut galaxies = vec![];
+
+    for (y, line) in grid.iter().enumerate() {
+      for (x, _) in line.iter().enumerate().filter(|(_, &galaxy)| galaxy) {
+        galaxies.push((x, y));
+      }
+    }
+
+    let mut empty_columns = vec![true; grid[0].len()];
+    let mut empty_rows = vec![true; grid.len()];
+
+    for &(x, y) in &galaxies {
+      empty_columns[x] = false;
+      empty_rows[y] = false;
+    }
+
+    let empty_columns = prefix_sum(empty_columns.into_iter());
+    let empty_rows = prefix_sum(empty_rows.into_iter());
+
+    Ok(Self {
+      galaxies,
+      empty_columns,
+      empty_rows,
+    })
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    if let Some(factor) = args.factor {
+      println!(
+        "Sum of distances with expansion factor {factor}: {}",
+        self.distance_sum(factor)
+      );
+    }
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(self.distance_sum(NonZeroU64::new(2).unwrap()).to_string())
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    Ok(
+      self
+        .distance_sum(NonZeroU64::new(1_000_000).unwrap())
+        .to_string(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn example() {
+    let mut day = Day11::setup(EXAMPLE).unwrap();
+    assert_eq!(day.part1().unwrap(), "374");
+    assert_eq!(day.part2().unwrap(), "82000210");
+  }
+}
@@ -0,0 +1,161 @@
+use crate::parse::{self, Line};
+use crate::Day;
+use itertools::Itertools;
+use rayon::prelude::*;
+
+pub struct Day12 {
+  records: Vec<Record>,
+}
+
+const EXAMPLE: &str = r#"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1"#;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Spring {
+  Operational,
+  Damaged,
+  Unknown,
+}
+
+impl Spring {
+  fn new(c: char) -> Option<Self> {
+    match c {
+      '.' => Some(Self::Operational),
+      '#' => Some(Self::Damaged),
+      '?' => Some(Self::Unknown),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Record {
+  springs: Vec<Spring>,
+  groups: Vec<usize>,
+}
+
+impl Record {
+  fn new(line: Line) -> anyhow::Result<Self> {
+    let (springs, groups) = line.split_once(line.text, " ")?;
+
+    let springs = springs
+      .char_indices()
+      .map(|(x, c)| Spring::new(c).ok_or_else(|| line.error(x, format!("unexpected spring {c:?}"))))
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let groups = groups
+      .split(',')
+      .map(|n| match line.parse(n)? {
+        0 => Err(line.error_at(n, "group has a length of zero")),
+        n => Ok(n),
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(Self { springs, groups })
+  }
+
+  /// Repeats the springs `times` times separated by unknowns, and the groups `times` times
+  fn unfold(&self, times: usize) -> Self {
+    Self {
+      springs: vec![self.springs.clone(); times].join(&Spring::Unknown),
+      groups: self.groups.repeat(times),
+    }
+  }
+
+  /// Counts every way the unknown springs could be filled in to match the groups.
+  ///
+  /// `counts[i][g]` is the number of arrangements of `springs[i..]` matching `groups[g..]`,
+  /// filled in from the back so each cell only looks at cells that are already done.
+  fn arrangements(&self) -> u128 {
+    let springs = &self.springs;
+    let groups = &self.groups;
+    let len = springs.len();
+
+    // How far a run of possibly damaged springs reaches from each index
+    let mut damaged_run = vec![0; len + 1];
+
+    for i in (0..len).rev() {
+      if springs[i] != Spring::Operational {
+        damaged_run[i] = damaged_run[i + 1] + 1;
+      }
+    }
+
+    // One extra column for `g == groups.len()` and one extra row for `i == len`
+    let mut counts = vec![vec![0u128; groups.len() + 1]; len + 2];
+    counts[len][groups.len()] = 1;
+    counts[len + 1][groups.len()] = 1;
+
+    for i in (0..len).rev() {
+      for g in (0..=groups.len()).rev() {
+        let mut count = 0;
+
+        // Treat this spring as operational
+        if springs[i] != Spring::Damaged {
+          count += counts[i + 1][g];
+        }
+
+        // Or start the next group here, which has to be followed by an operational spring
+        if let Some(&group) = groups.get(g) {
+          let fits = springs[i] != Spring::Operational
+            && damaged_run[i] >= group
+            && springs.get(i + group) != Some(&Spring::Damaged);
+
+          if fits {
+            count += counts[(i + group + 1).min(len + 1)][g + 1];
+          }
+        }
+
+        counts[i][g] = count;
+      }
+    }
+
+    counts[0][0]
+  }
+}
+
+impl Day12 {
+  fn sum_arrangements(&self, unfold: usize) -> u128 {
+    self
+      .records
+      .par_iter()
+      .map(|record| record.unfold(unfold).arrangements())
+      .sum()
+  }
+}
+
+impl<'a> Day<'a> for Day12 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let records = parse::lines(input).map(Record::new).try_collect()?;
+
+    Ok(Self { records })
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(self.sum_arrangements(1).to_string())
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    Ok(self.sum_arrangements(5).to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn example() {
+    let mut day = Day12::setup(EXAMPLE).unwrap();
+    assert_eq!(day.part1().unwrap(), "21");
+    assert_eq!(day.part2().unwrap(), "525152");
+  }
+
+  #[test]
+  fn zero_length_group() {
+    assert!(Day12::setup("??? 0").is_err());
+  }
+}
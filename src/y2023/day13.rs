@@ -0,0 +1,183 @@
+use crate::parse::{self, Line};
+use crate::{Args, Day};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+pub struct Day13 {
+  patterns: Vec<Pattern>,
+}
+
+const EXAMPLE: &str = r#"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#;
+
+/// A pattern stored both as rows and as columns, where each `#` is a set bit
+#[derive(Debug, Clone)]
+struct Pattern {
+  rows: Vec<u64>,
+  columns: Vec<u64>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Reflection {
+  /// Mirror between two rows, with this many rows above it
+  Horizontal(usize),
+  /// Mirror between two columns, with this many columns left of it
+  Vertical(usize),
+}
+
+impl Reflection {
+  fn summary(self) -> usize {
+    match self {
+      Reflection::Horizontal(n) => n * 100,
+      Reflection::Vertical(n) => n,
+    }
+  }
+}
+
+impl Display for Reflection {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Reflection::Horizontal(n) => write!(f, "horizontal, between rows {n} and {}", n + 1),
+      Reflection::Vertical(n) => write!(f, "vertical, between columns {n} and {}", n + 1),
+    }
+  }
+}
+
+/// Finds the mirror position where the lines on either side differ in exactly `smudges` cells
+fn find_mirror(lines: &[u64], smudges: u32) -> Option<usize> {
+  (1..lines.len()).find(|&mirror| {
+    let above = lines[..mirror].iter().rev();
+    let below = lines[mirror..].iter();
+
+    above
+      .zip(below)
+      .map(|(a, b)| (a ^ b).count_ones())
+      .sum::<u32>()
+      == smudges
+  })
+}
+
+impl Pattern {
+  fn new(block: &[Line]) -> anyhow::Result<Self> {
+    let width = block[0].text.len();
+
+    if width > u64::BITS as usize || block.len() > u64::BITS as usize {
+      return Err(block[0].error(
+        0,
+        format!("patterns can be at most {} cells wide and tall", u64::BITS),
+      ));
+    }
+
+    let mut rows = vec![0; block.len()];
+    let mut columns = vec![0; width];
+
+    for (y, line) in block.iter().enumerate() {
+      if line.text.len() != width {
+        return Err(line.error(
+          0,
+          format!("expected {width} cells like the rest of the pattern"),
+        ));
+      }
+
+      for (x, c) in line.text.char_indices() {
+        match c {
+          '#' => {
+            rows[y] |= 1 << x;
+            columns[x] |= 1 << y;
+          }
+          '.' => (),
+          _ => return Err(line.error(x, format!("unexpected character {c:?}"))),
+        }
+      }
+    }
+
+    Ok(Self { rows, columns })
+  }
+
+  fn reflection(&self, smudges: u32) -> Option<Reflection> {
+    find_mirror(&self.rows, smudges)
+      .map(Reflection::Horizontal)
+      .or_else(|| find_mirror(&self.columns, smudges).map(Reflection::Vertical))
+  }
+}
+
+impl Day13 {
+  /// Finds the reflection of every pattern, with exactly `smudges` cells differing across it
+  pub fn reflections(&self, smudges: u32) -> anyhow::Result<Vec<Reflection>> {
+    self
+      .patterns
+      .iter()
+      .enumerate()
+      .map(|(i, pattern)| {
+        pattern.reflection(smudges).ok_or_else(|| {
+          anyhow::anyhow!("Pattern {} has no reflection with {smudges} smudges", i + 1)
+        })
+      })
+      .collect()
+  }
+
+  fn summarize(&self, smudges: u32) -> anyhow::Result<String> {
+    Ok(
+      self
+        .reflections(smudges)?
+        .into_iter()
+        .map(Reflection::summary)
+        .sum::<usize>()
+        .to_string(),
+    )
+  }
+}
+
+impl<'a> Day<'a> for Day13 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let patterns = parse::blocks(input)
+      .iter()
+      .map(|block| Pattern::new(block))
+      .try_collect()?;
+
+    Ok(Self { patterns })
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    if let Some(smudges) = args.smudges {
+      for (i, reflection) in self.reflections(smudges)?.into_iter().enumerate() {
+        println!("Pattern {} with {smudges} smudges: {reflection}", i + 1);
+      }
+    }
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    self.summarize(0)
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    self.summarize(1)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn example() {
+    let mut day = Day13::setup(EXAMPLE).unwrap();
+    assert_eq!(day.part1().unwrap(), "405");
+    assert_eq!(day.part2().unwrap(), "400");
+  }
+}
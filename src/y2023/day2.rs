@@ -0,0 +1,245 @@
+use crate::parse::{self, Line};
+use crate::{Args, Day};
+use anyhow::Context;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Day2 {
+  games: Vec<Game>,
+  /// The bag part 1 checks every game against, [Bag::PUZZLE] unless configured with `--bag`
+  bag: Bag,
+}
+
+const EXAMPLE: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Color {
+  Red,
+  Green,
+  Blue,
+}
+
+impl FromStr for Color {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "red" => Ok(Self::Red),
+      "green" => Ok(Self::Green),
+      "blue" => Ok(Self::Blue),
+      _ => Err(anyhow::Error::msg("unknown color")),
+    }
+  }
+}
+
+/// A number of cubes of every color, used both for what is in the bag and for a set drawn from it
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+  pub red: u32,
+  pub green: u32,
+  pub blue: u32,
+}
+
+impl Bag {
+  /// The bag from the puzzle: 12 red, 13 green and 14 blue cubes
+  pub const PUZZLE: Bag = Bag {
+    red: 12,
+    green: 13,
+    blue: 14,
+  };
+
+  pub fn get(&self, color: Color) -> u32 {
+    match color {
+      Color::Red => self.red,
+      Color::Green => self.green,
+      Color::Blue => self.blue,
+    }
+  }
+
+  fn get_mut(&mut self, color: Color) -> &mut u32 {
+    match color {
+      Color::Red => &mut self.red,
+      Color::Green => &mut self.green,
+      Color::Blue => &mut self.blue,
+    }
+  }
+
+  /// Parses `part` of a line, a comma separated list of cubes like `3 blue, 4 red`,
+  /// missing colors have no cubes
+  fn parse(line: &Line, part: &str) -> anyhow::Result<Self> {
+    let mut bag = Bag::default();
+
+    for cubes in part.split(',').map(str::trim) {
+      let (n, color) = line.split_once(cubes, " ")?;
+
+      *bag.get_mut(line.parse(color)?) += line.parse::<u32>(n)?;
+    }
+
+    Ok(bag)
+  }
+
+  /// Whether every cube of `set` could have come out of this bag
+  pub fn holds(&self, set: &Bag) -> bool {
+    set.red <= self.red && set.green <= self.green && set.blue <= self.blue
+  }
+
+  /// The number of red, green and blue cubes multiplied together
+  pub fn power(&self) -> u32 {
+    self.red * self.green * self.blue
+  }
+}
+
+impl FromStr for Bag {
+  type Err = anyhow::Error;
+
+  /// Parses a bag like `12 red, 13 green, 14 blue`
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let line = Line { index: 0, text: s };
+
+    Bag::parse(&line, s)
+  }
+}
+
+pub struct Game {
+  pub id: u32,
+  /// Every set of cubes revealed during the game
+  pub sets: Vec<Bag>,
+}
+
+impl Game {
+  fn new(line: Line) -> anyhow::Result<Self> {
+    let (label, sets) = line.labelled()?;
+    let id = label
+      .strip_prefix("Game ")
+      .ok_or_else(|| line.error_at(label, "expected \"Game <id>\""))?;
+
+    Ok(Game {
+      id: line.parse(id)?,
+      sets: sets
+        .split(';')
+        .map(|set| Bag::parse(&line, set))
+        .try_collect()?,
+    })
+  }
+
+  /// The smallest bag this game could have been played with
+  pub fn minimum_bag(&self) -> Bag {
+    self.sets.iter().fold(Bag::default(), |min, set| Bag {
+      red: min.red.max(set.red),
+      green: min.green.max(set.green),
+      blue: min.blue.max(set.blue),
+    })
+  }
+
+  /// Whether this game could have been played with `bag`
+  pub fn possible_with(&self, bag: &Bag) -> bool {
+    self.sets.iter().all(|set| bag.holds(set))
+  }
+}
+
+impl Day2 {
+  pub fn games(&self) -> &[Game] {
+    &self.games
+  }
+
+  /// Sum of the ids of the games that could have been played with `bag`
+  pub fn sum_possible(&self, bag: &Bag) -> u32 {
+    self
+      .games
+      .iter()
+      .filter(|game| game.possible_with(bag))
+      .map(|game| game.id)
+      .sum()
+  }
+}
+
+impl<'a> Day<'a> for Day2 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let mut games = vec![];
+    let mut seen = HashMap::new();
+
+    for line in parse::lines(input) {
+      let game = Game::new(line)?;
+
+      if let Some(first) = seen.insert(game.id, line.index) {
+        return Err(line.error(
+          0,
+          format!(
+            "duplicate game {}, first seen on line {}",
+            game.id,
+            first + 1
+          ),
+        ));
+      }
+
+      games.push(game);
+    }
+
+    Ok(Day2 {
+      games,
+      bag: Bag::PUZZLE,
+    })
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    if let Some(bag) = &args.bag {
+      self.bag = bag.parse().context("Invalid --bag")?;
+    }
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(self.sum_possible(&self.bag).to_string())
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    Ok(
+      self
+        .games
+        .iter()
+        .map(|game| game.minimum_bag().power())
+        .sum::<u32>()
+        .to_string(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn example() {
+    let mut day = Day2::setup(EXAMPLE).unwrap();
+    assert_eq!(day.part1().unwrap(), "8");
+    assert_eq!(day.part2().unwrap(), "2286");
+  }
+
+  #[test]
+  fn shuffled_and_filtered_games() {
+    // Games 1, 2 and 5 are possible, game 4 is left out and the rest are shuffled
+    let lines = EXAMPLE.lines().collect_vec();
+    let input = [lines[4], lines[2], lines[0], lines[1]].join("\n");
+
+    assert_eq!(Day2::setup(&input).unwrap().part1().unwrap(), "8");
+  }
+
+  #[test]
+  fn duplicate_ids() {
+    let err = Day2::setup("Game 3: 1 red\nGame 1: 2 blue\nGame 3: 4 green")
+      .err()
+      .unwrap()
+      .to_string();
+
+    assert!(
+      err.contains("duplicate game 3, first seen on line 1"),
+      "{err}"
+    );
+  }
+}
@@ -0,0 +1,305 @@
+use crate::parse;
+use crate::{Args, Day};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter, Write};
+use std::io::IsTerminal;
+
+pub struct Day3 {
+  width: usize,
+  height: usize,
+  grid: Vec<Vec<Point>>,
+  number_groups: Vec<u32>,
+  /// For every point of the grid, row by row, the index into `number_groups` of the number on it
+  labels: Vec<Option<u32>>,
+}
+
+const EXAMPLE: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+
+/// A symbol of the schematic along with every number next to it
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Symbol {
+  pub x: usize,
+  pub y: usize,
+  pub symbol: char,
+  /// Indexes into [Day3::numbers] of every adjacent number, sorted and without duplicates
+  pub numbers: Vec<usize>,
+}
+
+/// How a point is highlighted by [Day3::render]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Highlight {
+  Part,
+  NotPart,
+  Symbol,
+  Gear,
+  Blank,
+}
+
+impl Highlight {
+  const LEGEND: [(Highlight, &'static str); 4] = [
+    (Highlight::Part, "part number"),
+    (Highlight::NotPart, "not a part number"),
+    (Highlight::Symbol, "symbol"),
+    (Highlight::Gear, "gear"),
+  ];
+
+  fn ansi(self) -> &'static str {
+    match self {
+      Highlight::Part => "\x1b[0;32m",
+      Highlight::NotPart => "\x1b[0;31m",
+      Highlight::Symbol => "\x1b[0;33m",
+      Highlight::Gear => "\x1b[0;1;35m",
+      Highlight::Blank => "\x1b[0;2m",
+    }
+  }
+
+  /// Marker for output without colors, drawn under the highlighted point
+  fn marker(self) -> char {
+    match self {
+      Highlight::Part => 'P',
+      Highlight::NotPart => '-',
+      Highlight::Symbol => 'S',
+      Highlight::Gear => 'G',
+      Highlight::Blank => ' ',
+    }
+  }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Point {
+  N(char),
+  Symbol(char),
+  Blank,
+}
+
+impl Point {
+  fn to_char(self) -> char {
+    match self {
+      Point::N(c) => c,
+      Point::Symbol(c) => c,
+      Point::Blank => '.',
+    }
+  }
+}
+
+impl From<char> for Point {
+  fn from(value: char) -> Self {
+    match value {
+      '.' => Point::Blank,
+      n if n.is_ascii_digit() => Point::N(value),
+      s => Point::Symbol(s),
+    }
+  }
+}
+
+impl Display for Point {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_char(self.to_char())
+  }
+}
+
+impl Day3 {
+  /// How every point is highlighted, row by row like `labels`
+  fn highlights(&self) -> Vec<Highlight> {
+    let mut parts = vec![false; self.number_groups.len()];
+    let mut highlights = self
+      .labels
+      .iter()
+      .map(|label| match label {
+        Some(_) => Highlight::NotPart,
+        None => Highlight::Blank,
+      })
+      .collect_vec();
+
+    for symbol in self.symbols() {
+      let gear = symbol.symbol == '*' && symbol.numbers.len() == 2;
+
+      highlights[symbol.y * self.width + symbol.x] = match gear {
+        true => Highlight::Gear,
+        false => Highlight::Symbol,
+      };
+
+      for &i in &symbol.numbers {
+        parts[i] = true;
+      }
+    }
+
+    for (highlight, label) in highlights.iter_mut().zip(&self.labels) {
+      if label.is_some_and(|label| parts[label as usize]) {
+        *highlight = Highlight::Part;
+      }
+    }
+
+    highlights
+  }
+
+  /// Draws the schematic with part numbers, other numbers, symbols and gears highlighted,
+  /// with colors when `ansi` is set or otherwise with a line of markers under every line
+  pub fn render(&self, ansi: bool) -> String {
+    let highlights = self.highlights();
+    let mut out = String::new();
+
+    let legend = Highlight::LEGEND
+      .iter()
+      .map(|&(highlight, name)| match ansi {
+        true => format!("{}{name}\x1b[0m", highlight.ansi()),
+        false => format!("{} {name}", highlight.marker()),
+      });
+    writeln!(out, "{}", legend.format(", ")).unwrap();
+
+    for (line, highlights) in self.grid.iter().zip(highlights.chunks(self.width)) {
+      if ansi {
+        for (point, highlight) in line.iter().zip(highlights) {
+          write!(out, "{}{point}", highlight.ansi()).unwrap();
+        }
+        writeln!(out, "\x1b[0m").unwrap();
+      } else {
+        writeln!(out, "{}", line.iter().join("")).unwrap();
+        let markers = highlights.iter().map(|highlight| highlight.marker());
+        writeln!(out, "{}", markers.collect::<String>().trim_end()).unwrap();
+      }
+    }
+
+    out
+  }
+
+  /// Label of the number at `(x, y)`, if there is one
+  fn label(&self, x: isize, y: isize) -> Option<usize> {
+    if !(0..self.width as isize).contains(&x) || !(0..self.height as isize).contains(&y) {
+      return None;
+    }
+
+    self.labels[y as usize * self.width + x as usize].map(|label| label as usize)
+  }
+
+  /// Every number of the schematic, in reading order
+  pub fn numbers(&self) -> &[u32] {
+    &self.number_groups
+  }
+
+  /// Indexes into [Day3::numbers] of every number next to `(x, y)`, sorted and without duplicates
+  pub fn adjacent_numbers(&self, x: usize, y: usize) -> Vec<usize> {
+    let (x, y) = (x as isize, y as isize);
+
+    (y - 1..=y + 1)
+      .cartesian_product(x - 1..=x + 1)
+      .filter_map(|(y, x)| self.label(x, y))
+      .sorted_unstable()
+      .dedup()
+      .collect_vec()
+  }
+
+  /// Every symbol of the schematic in reading order, along with its adjacent numbers
+  pub fn symbols(&self) -> impl Iterator<Item = Symbol> + '_ {
+    self.grid.iter().enumerate().flat_map(move |(y, line)| {
+      line
+        .iter()
+        .enumerate()
+        .filter_map(move |(x, point)| match point {
+          Point::Symbol(symbol) => Some(Symbol {
+            x,
+            y,
+            symbol: *symbol,
+            numbers: self.adjacent_numbers(x, y),
+          }),
+          _ => None,
+        })
+    })
+  }
+
+  /// Every `symbol` next to exactly `count` numbers
+  pub fn symbols_with(&self, symbol: char, count: usize) -> impl Iterator<Item = Symbol> + '_ {
+    self
+      .symbols()
+      .filter(move |it| it.symbol == symbol && it.numbers.len() == count)
+  }
+}
+
+impl<'a> Day<'a> for Day3 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let grid = parse::grid(input, |c| Some(Point::from(c)))?;
+
+    let width = grid[0].len();
+    let height = grid.len();
+
+    let mut number_groups = Vec::new();
+    let mut labels = vec![None; width * height];
+
+    for (y, line) in grid.iter().enumerate() {
+      let mut x = 0;
+
+      while x < width {
+        let start = x;
+        let mut n = 0u32;
+
+        while let Some(Point::N(c)) = line.get(x) {
+          n = n
+            .checked_mul(10)
+            .and_then(|n| n.checked_add(c.to_digit(10)?))
+            .ok_or_else(|| {
+              anyhow::anyhow!("Line {}, column {}: number is too large", y + 1, start + 1)
+            })?;
+          labels[y * width + x] = Some(number_groups.len() as u32);
+          x += 1;
+        }
+
+        if x == start {
+          x += 1;
+        } else {
+          number_groups.push(n);
+        }
+      }
+    }
+
+    Ok(Self {
+      width,
+      height,
+      grid,
+      number_groups,
+      labels,
+    })
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    if args.render {
+      print!("{}", self.render(std::io::stdout().is_terminal()));
+    }
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(
+      self
+        .symbols()
+        .flat_map(|symbol| symbol.numbers)
+        .unique()
+        .map(|i| self.number_groups[i])
+        .sum::<u32>()
+        .to_string(),
+    )
+  }
+
+  /// Gears are `*`s next to exactly two numbers
+  fn part2(&mut self) -> anyhow::Result<String> {
+    let mut sum = 0;
+
+    for gear in self.symbols_with('*', 2) {
+      let [a, b] = [0, 1].map(|i| self.number_groups[gear.numbers[i]]);
+
+      crate::debug!("Gear at ({}, {}): {a} * {b}", gear.x, gear.y);
+      sum += a * b;
+    }
+
+    Ok(sum.to_string())
+  }
+}
@@ -0,0 +1,217 @@
+use crate::parse::{self, Line};
+use crate::{Args, Day};
+use itertools::Itertools;
+
+pub struct Day4 {
+  cards: Vec<Card>,
+}
+
+const EXAMPLE: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+
+/// Set of the numbers `0..256`, one bit per number
+#[derive(Debug, Default, Copy, Clone)]
+struct NumberSet([u128; 2]);
+
+impl NumberSet {
+  const MAX: u32 = 255;
+
+  fn insert(&mut self, n: u32) {
+    self.0[n as usize / 128] |= 1 << (n % 128);
+  }
+
+  fn contains(&self, n: u32) -> bool {
+    n <= Self::MAX && self.0[n as usize / 128] & (1 << (n % 128)) != 0
+  }
+}
+
+/// How a card ended up with its copies, see [Day4::breakdown]
+#[derive(Debug, Clone)]
+pub struct CardCopies {
+  pub id: u32,
+  pub matches: usize,
+  /// Copies held at the end, including the original
+  pub copies: usize,
+  /// Copies won from earlier cards, as `(card id, copies)`
+  pub won_from: Vec<(u32, usize)>,
+  /// Copies of later cards won with this card
+  pub produced: usize,
+}
+
+#[derive(Debug)]
+struct Card {
+  id: u32,
+  winning: NumberSet,
+  numbers: Vec<u32>,
+}
+
+impl Card {
+  fn new(line: Line) -> anyhow::Result<Self> {
+    let (label, rest) = line.labelled()?;
+    let id = label
+      .strip_prefix("Card")
+      .ok_or_else(|| line.error_at(label, "expected \"Card <id>\""))?;
+    let (winning, numbers) = line.split_once(rest, "|")?;
+
+    let mut set = NumberSet::default();
+
+    for n in line.integers_in(winning)? {
+      if n > NumberSet::MAX {
+        return Err(line.error_at(
+          winning,
+          format!("winning number {n} is over {}", NumberSet::MAX),
+        ));
+      }
+
+      set.insert(n);
+    }
+
+    Ok(Self {
+      id: line.parse(id)?,
+      winning: set,
+      numbers: line.integers_in(numbers)?,
+    })
+  }
+
+  fn count_winning(&self) -> usize {
+    self
+      .numbers
+      .iter()
+      .filter(|&&n| self.winning.contains(n))
+      .count()
+  }
+
+  /// 1 point for the first match, doubled for every match after that
+  fn count_winning_score(&self) -> anyhow::Result<u128> {
+    match self.count_winning() {
+      0 => Ok(0),
+      numbers => 1u128.checked_shl(numbers as u32 - 1).ok_or_else(|| {
+        anyhow::anyhow!("Score of card {} with {numbers} matches overflows", self.id)
+      }),
+    }
+  }
+}
+
+impl Day4 {
+  /// Copies held of every card, including the original
+  fn copies(&self) -> Vec<usize> {
+    let mut cards = vec![1; self.cards.len()];
+
+    for (i, card) in self.cards.iter().enumerate() {
+      let winning = card.count_winning();
+      let times = cards[i];
+
+      for j in 1..=winning {
+        if let Some(n) = cards.get_mut(i + j) {
+          *n += times;
+        }
+      }
+    }
+
+    cards
+  }
+
+  /// For every card, how many copies it ended up with and where they came from
+  pub fn breakdown(&self) -> Vec<CardCopies> {
+    let copies = self.copies();
+    let mut breakdown = self
+      .cards
+      .iter()
+      .zip(&copies)
+      .map(|(card, &copies)| CardCopies {
+        id: card.id,
+        matches: card.count_winning(),
+        copies,
+        won_from: vec![],
+        produced: 0,
+      })
+      .collect_vec();
+
+    for i in 0..breakdown.len() {
+      let (id, matches) = (breakdown[i].id, breakdown[i].matches);
+
+      for won in breakdown.iter_mut().skip(i + 1).take(matches) {
+        won.won_from.push((id, copies[i]));
+      }
+
+      breakdown[i].produced = copies[i] * matches.min(copies.len() - i - 1);
+    }
+
+    breakdown
+  }
+
+  /// Prints [Day4::breakdown] along with the card that produced the most copies
+  fn explain(&self) {
+    let breakdown = self.breakdown();
+
+    for card in &breakdown {
+      let sources = std::iter::once(String::from("1 original"))
+        .chain(
+          card
+            .won_from
+            .iter()
+            .map(|(id, copies)| format!("{copies} from card {id}")),
+        )
+        .join(", ");
+
+      println!(
+        "Card {}: {} matches, {} copies ({sources})",
+        card.id, card.matches, card.copies
+      );
+    }
+
+    if let Some(card) = breakdown.iter().max_by_key(|card| card.produced) {
+      println!(
+        "Card {} produced the most copies: {}",
+        card.id, card.produced
+      );
+    }
+  }
+}
+
+impl<'a> Day<'a> for Day4 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let mut cards = vec![];
+
+    for line in parse::lines(input) {
+      let card = Card::new(line)?;
+      let expected = cards.len() as u32 + 1;
+
+      if card.id != expected {
+        return Err(line.error(0, format!("expected card {expected}, got card {}", card.id)));
+      }
+
+      cards.push(card);
+    }
+
+    Ok(Self { cards })
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    if args.explain {
+      self.explain();
+    }
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    let mut sum = 0u128;
+
+    for card in &self.cards {
+      sum = sum
+        .checked_add(card.count_winning_score()?)
+        .ok_or_else(|| anyhow::Error::msg("Sum of the scores overflows"))?;
+    }
+
+    Ok(sum.to_string())
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    Ok(self.copies().iter().sum::<usize>().to_string())
+  }
+}
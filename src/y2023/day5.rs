@@ -0,0 +1,385 @@
+use crate::iter::IteratorExt;
+use crate::parse::{self, Line};
+use crate::Day;
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::ops::Range;
+
+pub struct Day5<'a> {
+  layers: Vec<Layer<'a>>,
+  seeds: Vec<usize>,
+  /// The `seeds:` line, for errors about the seed ranges of part 2
+  seeds_line: Line<'a>,
+}
+
+const EXAMPLE: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"#;
+
+#[derive(Debug, Clone)]
+struct Map {
+  dest: Range<usize>,
+  src: Range<usize>,
+}
+
+/// One of the almanac's maps, like `seed-to-soil`
+#[derive(Debug, Clone)]
+struct Layer<'a> {
+  name: &'a str,
+  maps: Vec<Map>,
+}
+
+fn lookup(n: usize, maps: &[Map]) -> usize {
+  let Some(map) = maps.iter().find(|map| map.src.contains(&n)) else {
+    return n;
+  };
+
+  let distance = n - map.src.start;
+
+  map.dest.start + distance
+}
+
+/// Sorts `ranges` and merges the ones that overlap or touch
+fn merge(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+  ranges.sort_unstable_by_key(|range| range.start);
+
+  let mut merged: Vec<Range<usize>> = vec![];
+
+  for range in ranges.into_iter().filter(|range| !range.is_empty()) {
+    match merged.last_mut() {
+      Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+      _ => merged.push(range),
+    }
+  }
+
+  merged
+}
+
+/// Every value [lookup] turns into a value of `range`, sorted and merged
+fn reverse_lookup(range: &Range<usize>, maps: &[Map]) -> Vec<Range<usize>> {
+  let mut sources = vec![];
+
+  for map in maps {
+    let start = range.start.max(map.dest.start);
+    let end = range.end.min(map.dest.end);
+
+    if start < end {
+      let offset = map.src.start;
+      sources.push(offset + (start - map.dest.start)..offset + (end - map.dest.start));
+    }
+  }
+
+  // Values outside of every map are left as they are
+  let mut unmapped = vec![range.clone()];
+
+  for map in maps {
+    unmapped = unmapped
+      .into_iter()
+      .flat_map(|r| {
+        [
+          r.start..r.end.min(map.src.start),
+          r.start.max(map.src.end)..r.end,
+        ]
+      })
+      .filter(|r| !r.is_empty())
+      .collect_vec();
+  }
+
+  sources.extend(unmapped);
+
+  merge(sources)
+}
+
+/// Parses a `destination source length` line, erroring on empty ranges and ones that overflow
+fn parse_map(line: Line) -> anyhow::Result<Map> {
+  let parts = line.text.split_whitespace().collect_vec();
+  let &[dest, src, len] = parts.as_slice() else {
+    return Err(line.error(0, "expected destination start, source start and length"));
+  };
+
+  let n = line.parse::<usize>(len)?;
+
+  if n == 0 {
+    return Err(line.error_at(len, "map has a length of zero"));
+  }
+
+  let range = |part| -> anyhow::Result<Range<usize>> {
+    let start = line.parse::<usize>(part)?;
+    let end = start
+      .checked_add(n)
+      .ok_or_else(|| line.error_at(part, format!("{start} + {n} overflows")))?;
+
+    Ok(start..end)
+  };
+
+  Ok(Map {
+    dest: range(dest)?,
+    src: range(src)?,
+  })
+}
+
+/// Parses a map header and its lines, erroring if any of the source ranges overlap
+fn parse_layer<'a>(block: &[Line<'a>]) -> anyhow::Result<Layer<'a>> {
+  let header = block[0];
+  let name = header
+    .text
+    .strip_suffix(" map:")
+    .ok_or_else(|| header.error(0, "expected a map header like `seed-to-soil map:`"))?;
+
+  let lines = &block[1..];
+  let maps: Vec<Map> = lines.iter().copied().map(parse_map).try_collect()?;
+
+  let overlap = (0..maps.len())
+    .sorted_by_key(|&i| maps[i].src.start)
+    .tuple_windows()
+    .find(|&(a, b)| maps[b].src.start < maps[a].src.end);
+
+  if let Some((a, b)) = overlap {
+    let (first, second) = (a.min(b), a.max(b));
+
+    return Err(lines[second].error(
+      0,
+      format!(
+        "source range {:?} of {name} overlaps {:?} from line {}",
+        maps[second].src,
+        maps[first].src,
+        lines[first].index + 1
+      ),
+    ));
+  }
+
+  Ok(Layer { name, maps })
+}
+
+impl<'a> Day5<'a> {
+  fn min_location(&self, seeds: impl ParallelIterator<Item = usize>) -> anyhow::Result<usize> {
+    seeds
+      .map(|n| {
+        self
+          .layers
+          .iter()
+          .fold(n, |n, layer| lookup(n, &layer.maps))
+      })
+      .min()
+      .ok_or_else(|| anyhow::Error::msg("No seeds to plant"))
+  }
+
+  /// Every seed that ends up at a location of `locations`, sorted and merged
+  pub fn seeds_for(&self, locations: Range<usize>) -> Vec<Range<usize>> {
+    self
+      .layers
+      .iter()
+      .rev()
+      .fold(vec![locations], |ranges, layer| {
+        let sources = merge(
+          ranges
+            .iter()
+            .flat_map(|range| reverse_lookup(range, &layer.maps))
+            .collect_vec(),
+        );
+
+        crate::trace!("{}: {ranges:?} <- {sources:?}", layer.name);
+
+        sources
+      })
+  }
+
+  /// Every seed that ends up at `location`, errors for `usize::MAX` since it can't end a range
+  pub fn seeds_at(&self, location: usize) -> anyhow::Result<Vec<Range<usize>>> {
+    let end = location
+      .checked_add(1)
+      .ok_or_else(|| anyhow::anyhow!("Location {location} is too large to look up"))?;
+
+    Ok(self.seeds_for(location..end))
+  }
+
+  /// Lowest location of any of `seeds`, found by walking the locations upward
+  /// and only looking into the halves that some of the seeds end up in
+  fn min_location_of_ranges(&self, seeds: &[Range<usize>]) -> anyhow::Result<usize> {
+    // Nothing maps past the end of every range, so the lowest location has to be below it
+    let end = self
+      .layers
+      .iter()
+      .flat_map(|layer| &layer.maps)
+      .flat_map(|map| [map.src.end, map.dest.end])
+      .chain(seeds.iter().map(|seeds| seeds.end))
+      .max()
+      .unwrap_or(0);
+
+    let mut pending = vec![];
+    pending.push(0..end);
+
+    while let Some(locations) = pending.pop() {
+      let reached = self.seeds_for(locations.clone()).iter().any(|range| {
+        seeds
+          .iter()
+          .any(|seeds| seeds.start < range.end && range.start < seeds.end)
+      });
+
+      if !reached {
+        continue;
+      }
+
+      if locations.len() == 1 {
+        return Ok(locations.start);
+      }
+
+      let middle = locations.start + locations.len() / 2;
+      pending.push(middle..locations.end);
+      pending.push(locations.start..middle);
+    }
+
+    Err(anyhow::Error::msg("No seeds to plant"))
+  }
+}
+impl<'a> Day<'a> for Day5<'a> {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let blocks = parse::blocks(input);
+
+    let Some((seeds, layers)) = blocks.split_first() else {
+      return Err(anyhow::Error::msg("Input is empty"));
+    };
+
+    let seeds_line = seeds[0];
+    let seeds = seeds_line.integers_in(seeds_line.expect_label("seeds")?)?;
+    let layers = layers
+      .iter()
+      .map(|block| parse_layer(block))
+      .try_collect()?;
+
+    Ok(Self {
+      layers,
+      seeds,
+      seeds_line,
+    })
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    // 265018614
+    Ok(
+      self
+        .min_location(self.seeds.par_iter().copied())?
+        .to_string(),
+    )
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    let seeds = self
+      .seeds
+      .iter()
+      .arrays::<2>()
+      .map(|[&start, &len]| {
+        let end = start.checked_add(len).ok_or_else(|| {
+          let line = &self.seeds_line;
+          line.error(0, format!("seed range {start} + {len} overflows"))
+        })?;
+
+        Ok(start..end)
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(self.min_location_of_ranges(&seeds)?.to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn error(input: &str) -> String {
+    Day5::setup(input).err().unwrap().to_string()
+  }
+
+  #[test]
+  fn example() {
+    let mut day = Day5::setup(EXAMPLE).unwrap();
+    assert_eq!(day.part1().unwrap(), "35");
+    assert_eq!(day.part2().unwrap(), "46");
+  }
+
+  #[test]
+  fn seeds_for() {
+    let day = Day5::setup(EXAMPLE).unwrap();
+
+    assert!(day
+      .seeds_at(46)
+      .unwrap()
+      .iter()
+      .any(|range| range.contains(&82)));
+    assert!(day.seeds_at(usize::MAX).is_err());
+
+    // Looking up every location gives back merged, sorted ranges covering the seeds
+    let all = day.seeds_for(0..usize::MAX);
+    assert!(all.iter().any(|range| range.contains(&0)));
+    assert!(all.iter().any(|range| range.contains(&79)));
+    assert!(all.windows(2).all(|w| w[0].end < w[1].start), "{all:?}");
+  }
+
+  #[test]
+  fn zero_length() {
+    let err = error("seeds: 1\n\nseed-to-soil map:\n50 98 0");
+    assert!(err.contains("map has a length of zero"), "{err}");
+  }
+
+  #[test]
+  fn overflow() {
+    let err = error(&format!(
+      "seeds: 1\n\nseed-to-soil map:\n0 {} 2",
+      usize::MAX
+    ));
+    assert!(
+      err.contains(&format!("{} + 2 overflows", usize::MAX)),
+      "{err}"
+    );
+
+    let input = format!("seeds: {} 2\n\nseed-to-soil map:\n0 5 1", usize::MAX);
+    let mut day = Day5::setup(&input).unwrap();
+    let err = day.part2().unwrap_err().to_string();
+    assert!(
+      err.contains(&format!("seed range {} + 2 overflows", usize::MAX)),
+      "{err}"
+    );
+  }
+
+  #[test]
+  fn overlap() {
+    let err = error("seeds: 1\n\nseed-to-soil map:\n50 98 2\n0 10 5\n52 99 3");
+    assert!(
+      err.contains("source range 99..102 of seed-to-soil overlaps 98..100 from line 4"),
+      "{err}"
+    );
+
+    // Touching ranges don't overlap
+    assert!(Day5::setup("seeds: 1\n\nseed-to-soil map:\n50 98 2\n0 100 5").is_ok());
+  }
+}
@@ -0,0 +1,60 @@
+use crate::parse;
+use crate::Day;
+use itertools::Itertools;
+
+pub struct Day6 {
+  times: Vec<u64>,
+  distances: Vec<u64>,
+}
+
+const EXAMPLE: &str = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+/// Number of ways to hold the button that beat the record `distance`
+fn ways_to_win(time: u64, distance: u64) -> usize {
+  (1..=time).filter(|&n| (time - n) * n > distance).count()
+}
+
+impl<'a> Day<'a> for Day6 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let lines = parse::lines(input).collect_vec();
+
+    let &[time_line, distance_line] = lines.as_slice() else {
+      return Err(anyhow::Error::msg(
+        "Expected a time line and a distance line",
+      ));
+    };
+
+    let times = time_line.integers_in(time_line.expect_label("Time")?)?;
+    let distances = distance_line.integers_in(distance_line.expect_label("Distance")?)?;
+
+    if times.len() != distances.len() {
+      return Err(distance_line.error(
+        0,
+        format!("expected {} distances, one for every time", times.len()),
+      ));
+    }
+
+    Ok(Self { times, distances })
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(
+      self
+        .times
+        .iter()
+        .zip(&self.distances)
+        .map(|(&time, &distance)| ways_to_win(time, distance))
+        .product::<usize>()
+        .to_string(),
+    )
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    // The spaces between numbers are just bad kerning, so it's really one big number
+    let time = self.times.iter().join("").parse::<u64>()?;
+    let distance = self.distances.iter().join("").parse::<u64>()?;
+
+    Ok(ways_to_win(time, distance).to_string())
+  }
+}
@@ -4,10 +4,12 @@ use std::fmt::{Display, Formatter, Write};
 
 use itertools::Itertools;
 
+use crate::parse::{self, Line};
 use crate::Day;
 
-pub struct Day7<'a> {
-  input: &'a str,
+pub struct Day7 {
+  /// Every hand, with its bid
+  hands: Vec<(Hand, usize)>,
 }
 
 const EXAMPLE: &str = r#"2345A 1
@@ -50,8 +52,8 @@ enum Card {
 }
 
 impl Card {
-  fn new(c: char, joker_weak: bool) -> Self {
-    match c {
+  fn new(c: char, joker_weak: bool) -> Option<Self> {
+    let card = match c {
       'A' => Self::A,
       'K' => Self::K,
       'Q' => Self::Q,
@@ -66,8 +68,10 @@ impl Card {
       '4' => Self::N4,
       '3' => Self::N3,
       '2' => Self::N2,
-      _ => unreachable!(),
-    }
+      _ => return None,
+    };
+
+    Some(card)
   }
 }
 
@@ -77,15 +81,28 @@ struct Hand {
 }
 
 impl Hand {
-  fn new(cards: &str, joker_wildcard: bool) -> Self {
-    Self {
-      cards: cards
-        .chars()
-        .map(|c| Card::new(c, joker_wildcard))
-        .array_chunks::<5>()
-        .next()
-        .unwrap(),
-    }
+  /// Parses the `cards` part of a line
+  fn new(line: &Line, cards: &str) -> anyhow::Result<Self> {
+    let column = line.column_of(cards);
+    let cards = cards
+      .char_indices()
+      .map(|(i, c)| {
+        Card::new(c, false).ok_or_else(|| line.error(column + i, format!("unknown card {c:?}")))
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let cards = <[Card; 5]>::try_from(cards)
+      .map_err(|cards| line.error(column, format!("expected 5 cards, got {}", cards.len())))?;
+
+    Ok(Self { cards })
+  }
+
+  /// The same hand, with every `J` turned into a weak wildcard
+  fn with_jokers(self) -> Self {
+    let cards = self
+      .cards
+      .map(|card| if card == Card::J { Card::JWeak } else { card });
+    Self { cards }
   }
 
   fn strength(&self) -> usize {
@@ -133,39 +150,47 @@ impl PartialOrd<Self> for Hand {
   }
 }
 
-impl<'a> Day<'a> for Day7<'a> {
-  fn setup(input: &'a str) -> Self {
-    // Self { input: EXAMPLE }
-    Self { input }
-  }
-
-  // Real: 253205868
-  // Example: 6592
-  fn part1(&mut self) -> String {
+impl Day7 {
+  /// Sum of every bid multiplied by the rank of its hand
+  fn winnings(&self, joker_wildcard: bool) -> usize {
     self
-      .input
-      .lines()
-      .map(|line| line.split_once(' ').unwrap())
-      .map(|(cards, bid)| (Hand::new(cards, false), bid.parse::<usize>().unwrap()))
+      .hands
+      .iter()
+      .map(|&(hand, bid)| {
+        if joker_wildcard {
+          (hand.with_jokers(), bid)
+        } else {
+          (hand, bid)
+        }
+      })
       .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
       .enumerate()
       .map(|(index, (_, bid))| bid * (index + 1))
-      .sum::<usize>()
-      .to_string()
+      .sum()
+  }
+}
+
+impl<'a> Day<'a> for Day7 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let mut hands = vec![];
+
+    for line in parse::lines(input) {
+      let (cards, bid) = line.split_once(line.text, " ")?;
+      hands.push((Hand::new(&line, cards)?, line.parse(bid)?));
+    }
+
+    Ok(Self { hands })
+  }
+
+  // Real: 253205868
+  // Example: 6592
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(self.winnings(false).to_string())
   }
 
   // Real: 253907829
   // Example: 6839
-  fn part2(&mut self) -> String {
-    self
-      .input
-      .lines()
-      .map(|line| line.split_once(' ').unwrap())
-      .map(|(cards, bid)| (Hand::new(cards, true), bid.parse::<usize>().unwrap()))
-      .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
-      .enumerate()
-      .map(|(index, (_, bid))| bid * (index + 1))
-      .sum::<usize>()
-      .to_string()
+  fn part2(&mut self) -> anyhow::Result<String> {
+    Ok(self.winnings(true).to_string())
   }
 }
@@ -0,0 +1,424 @@
+use crate::parse;
+use crate::{Args, Day};
+use indexmap::IndexMap;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+#[derive(Debug)]
+pub struct Day8<'a> {
+  instructions: Vec<Instruction>,
+  network: IndexMap<&'a str, (&'a str, &'a str)>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Instruction {
+  Left,
+  Right,
+}
+
+impl Instruction {
+  fn new(c: char) -> Option<Self> {
+    match c {
+      'L' => Some(Self::Left),
+      'R' => Some(Self::Right),
+      _ => None,
+    }
+  }
+}
+
+const EXAMPLE: &str = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#;
+
+const EXAMPLE2: &str = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+
+impl<'a> Day<'a> for Day8<'a> {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let blocks = parse::blocks(input);
+
+    let [instructions, nodes] = blocks.as_slice() else {
+      return Err(anyhow::Error::msg(
+        "Expected instructions and a network separated by a blank line",
+      ));
+    };
+
+    let line = instructions[0];
+    let instructions = line
+      .text
+      .char_indices()
+      .map(|(column, c)| {
+        Instruction::new(c).ok_or_else(|| line.error(column, format!("unknown instruction {c:?}")))
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let network = nodes
+      .iter()
+      .map(|line| {
+        let (src, dst) = line.split_once(line.text, " = ")?;
+        let dst = dst
+          .strip_prefix('(')
+          .and_then(|dst| dst.strip_suffix(')'))
+          .ok_or_else(|| line.error_at(dst, "expected `(left, right)`"))?;
+        let (left, right) = line.split_once(dst, ", ")?;
+
+        Ok((src, (left, right)))
+      })
+      .collect::<anyhow::Result<IndexMap<_, _>>>()?;
+
+    Ok(Self {
+      instructions,
+      network,
+    })
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    if let Some(path) = &args.dot {
+      std::fs::write(path, self.to_dot(args.dot_ghosts)?)?;
+    }
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    Ok(self.walk("AAA", |node| node == "ZZZ")?.to_string())
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    let counters = self
+      .network
+      .keys()
+      .filter(|src| src.ends_with('A'))
+      .map(|src| self.walk(src, |node| node.ends_with('Z')))
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if counters.is_empty() {
+      return Err(anyhow::Error::msg(
+        "No ghosts to walk, expected nodes ending in A to start from",
+      ));
+    }
+
+    let steps = iter_lcm(counters.iter().copied()).ok_or_else(|| {
+      anyhow::anyhow!("Least common multiple of the ghost walks {counters:?} overflows")
+    })?;
+
+    Ok(steps.to_string())
+  }
+}
+
+impl<'a> Day8<'a> {
+  /// Follows the instructions from `start` until `is_end` matches,
+  /// returning how many steps it took.
+  fn walk(&self, start: &'a str, is_end: impl Fn(&str) -> bool) -> anyhow::Result<u64> {
+    Ok(self.trace(start, is_end)?.len() as u64)
+  }
+
+  /// Follows the instructions from `start` until `is_end` matches,
+  /// returning every visited node along with the instruction index taken from it.
+  ///
+  /// Since the walk is fully determined by the current node and the position
+  /// in the instructions, seeing the same pair twice means the end can never be reached.
+  fn trace(
+    &self,
+    start: &'a str,
+    is_end: impl Fn(&str) -> bool,
+  ) -> anyhow::Result<Vec<(&'a str, usize)>> {
+    if self.instructions.is_empty() {
+      return Err(anyhow::Error::msg("No instructions to follow"));
+    }
+
+    let mut path = Vec::new();
+    let mut seen = HashMap::new();
+    let mut current = start;
+
+    for (index, instruction) in self.instructions.iter().enumerate().cycle() {
+      if is_end(current) {
+        return Ok(path);
+      }
+
+      if let Some(&cycle_start) = seen.get(&(current, index)) {
+        let cycle = path[cycle_start..]
+          .iter()
+          .map(|&(node, _)| node)
+          .chain([current])
+          .join(" -> ");
+
+        return Err(anyhow::anyhow!(
+          "End is unreachable from {start}, stuck in a cycle of {} steps: {cycle}",
+          path.len() - cycle_start
+        ));
+      }
+
+      seen.insert((current, index), path.len());
+      path.push((current, index));
+
+      let Some((left, right)) = self.network.get(current) else {
+        return Err(anyhow::anyhow!(
+          "Node {current} does not exist (walking from {start})"
+        ));
+      };
+
+      current = match instruction {
+        Instruction::Left => left,
+        Instruction::Right => right,
+      };
+    }
+
+    unreachable!()
+  }
+
+  /// Follows the instructions from `start` until a (node, instruction index) pair repeats,
+  /// returning every visited pair and the position in the path where the repeating cycle begins.
+  ///
+  /// The cycle is [None] if the walk runs into a node missing from the network,
+  /// in which case the path stops at the last existing node.
+  fn orbit(&self, start: &'a str) -> (Vec<(&'a str, usize)>, Option<usize>) {
+    let mut path = Vec::new();
+    let mut seen = HashMap::new();
+    let mut current = start;
+
+    for (index, instruction) in self.instructions.iter().enumerate().cycle() {
+      if let Some(&cycle_start) = seen.get(&(current, index)) {
+        return (path, Some(cycle_start));
+      }
+
+      let Some((left, right)) = self.network.get(current) else {
+        break;
+      };
+
+      seen.insert((current, index), path.len());
+      path.push((current, index));
+
+      current = match instruction {
+        Instruction::Left => left,
+        Instruction::Right => right,
+      };
+    }
+
+    (path, None)
+  }
+
+  /// Renders the network as a Graphviz DOT graph, start nodes are green and end nodes are red.
+  ///
+  /// With `ghosts`, every edge a ghost takes until its walk repeats is coloured and labelled
+  /// with the ghost's start node, and the nodes it passes through are annotated with the steps
+  /// at which it reaches them, so the `Z -> ... -> Z` cycles stand out.
+  fn to_dot(&self, ghosts: bool) -> anyhow::Result<String> {
+    const COLORS: &[&str] = &["blue", "darkorange", "purple", "deeppink", "teal", "brown"];
+
+    let mut taken = HashMap::<(&str, Instruction), Vec<usize>>::new();
+    let mut notes = HashMap::<&str, Vec<String>>::new();
+    let starts = self
+      .network
+      .keys()
+      .filter(|node| node.ends_with('A'))
+      .collect_vec();
+
+    if ghosts {
+      for (ghost, start) in starts.iter().enumerate() {
+        let (path, cycle_start) = self.orbit(start);
+
+        for &(node, index) in &path {
+          let edge = (node, self.instructions[index]);
+          let ghosts = taken.entry(edge).or_default();
+
+          if !ghosts.contains(&ghost) {
+            ghosts.push(ghost);
+          }
+        }
+
+        let ends = path
+          .iter()
+          .enumerate()
+          .filter(|(_, (node, _))| node.ends_with('Z'))
+          .collect_vec();
+
+        let steps = ends
+          .iter()
+          .map(|&(step, &(node, _))| (node, step))
+          .into_group_map();
+
+        for (node, steps) in steps {
+          // Ends reached before the cycle begins are only seen once
+          let (once, repeated): (Vec<_>, Vec<_>) = steps
+            .into_iter()
+            .partition(|&step| cycle_start.is_none_or(|cycle_start| step < cycle_start));
+
+          let mut parts = vec![];
+          if !once.is_empty() {
+            parts.push(format!("step {} only", once.iter().join(", ")));
+          }
+          if let (false, Some(cycle_start)) = (repeated.is_empty(), cycle_start) {
+            parts.push(format!(
+              "step {}, then every {}",
+              repeated.iter().join(", "),
+              path.len() - cycle_start
+            ));
+          }
+
+          notes
+            .entry(node)
+            .or_default()
+            .push(format!("{start}: {}", parts.join("; ")));
+        }
+
+        let note = match (cycle_start, path.last()) {
+          (_, None) => format!("{start}: no instructions to follow"),
+          (None, Some((node, _))) => format!("{start}: stops at {node}, its next node is missing"),
+          (Some(cycle_start), _) if ends.is_empty() => format!(
+            "{start}: never ends, loops every {} steps after step {cycle_start}",
+            path.len() - cycle_start
+          ),
+          (Some(cycle_start), _) => format!(
+            "{start}: loops every {} steps after step {cycle_start}",
+            path.len() - cycle_start
+          ),
+        };
+
+        notes.entry(start).or_default().push(note);
+      }
+    }
+
+    let mut dot = String::new();
+
+    writeln!(dot, "digraph network {{")?;
+    writeln!(dot, "  node [shape=box, fontname=monospace];")?;
+
+    for (src, (left, right)) in &self.network {
+      let xlabel = match notes.get(src) {
+        Some(notes) => format!(", xlabel=\"{}\"", notes.join("\\n")),
+        None => String::new(),
+      };
+
+      if src.ends_with('A') {
+        writeln!(
+          dot,
+          "  {src:?} [style=filled, fillcolor=palegreen{xlabel}];"
+        )?;
+      } else if src.ends_with('Z') {
+        writeln!(
+          dot,
+          "  {src:?} [style=filled, fillcolor=lightcoral{xlabel}];"
+        )?;
+      } else if !xlabel.is_empty() {
+        writeln!(dot, "  {src:?} [{}];", &xlabel[2..])?;
+      }
+
+      for (dst, instruction, label) in [
+        (left, Instruction::Left, "L"),
+        (right, Instruction::Right, "R"),
+      ] {
+        match taken.get(&(src, instruction)) {
+          Some(ghosts) => {
+            let color = ghosts.iter().map(|&i| COLORS[i % COLORS.len()]).join(":");
+            let names = ghosts.iter().map(|&i| starts[i]).join(", ");
+
+            writeln!(
+              dot,
+              "  {src:?} -> {dst:?} [label=\"{label} ({names})\", color=\"{color}\", penwidth=2];"
+            )?;
+          }
+          None => writeln!(dot, "  {src:?} -> {dst:?} [label={label}];")?,
+        }
+      }
+    }
+
+    writeln!(dot, "}}")?;
+
+    Ok(dot)
+  }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+  if b == 0 {
+    a
+  } else {
+    gcd(b, a % b)
+  }
+}
+
+/// Least common multiple, [None] if it overflows
+fn lcm(a: u64, b: u64) -> Option<u64> {
+  if a == 0 || b == 0 {
+    Some(0)
+  } else {
+    (a / gcd(a, b)).checked_mul(b)
+  }
+}
+
+fn iter_lcm(mut numbers: impl Iterator<Item = u64>) -> Option<u64> {
+  numbers.try_fold(1, lcm)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn examples() {
+    let first = r#"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"#;
+
+    assert_eq!(Day8::setup(first).unwrap().part1().unwrap(), "2");
+    assert_eq!(Day8::setup(EXAMPLE).unwrap().part1().unwrap(), "6");
+    assert_eq!(Day8::setup(EXAMPLE2).unwrap().part2().unwrap(), "6");
+  }
+
+  #[test]
+  fn unreachable_end() {
+    let mut day = Day8::setup("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+    let err = day.part1().unwrap_err().to_string();
+
+    assert!(
+      err.contains("stuck in a cycle of 2 steps: AAA -> BBB -> AAA"),
+      "{err}"
+    );
+  }
+
+  #[test]
+  fn missing_start() {
+    let mut day = Day8::setup("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+    let err = day.part1().unwrap_err().to_string();
+
+    assert!(err.contains("Node AAA does not exist"), "{err}");
+  }
+
+  #[test]
+  fn dot_ghosts() {
+    let day = Day8::setup(EXAMPLE2).unwrap();
+    let dot = day.to_dot(true).unwrap();
+
+    assert!(
+      dot.contains(r#"xlabel="22A: step 3, 6, then every 6""#),
+      "{dot}"
+    );
+
+    // A ghost that never reaches an end is still drawn up to its first repeated state
+    let day = Day8::setup("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+    let dot = day.to_dot(true).unwrap();
+
+    assert!(
+      dot.contains("never ends, loops every 2 steps after step 0"),
+      "{dot}"
+    );
+    assert!(dot.contains(r#""BBB" -> "AAA" [label="L (AAA)""#), "{dot}");
+  }
+}
@@ -0,0 +1,223 @@
+use crate::parse;
+use crate::{Args, Day};
+use itertools::Itertools;
+use num_bigint::BigInt;
+
+pub struct Day9 {
+  nums: Vec<Vec<i64>>,
+}
+
+const EXAMPLE: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+
+impl<'a> Day<'a> for Day9 {
+  fn setup(input: &'a str) -> anyhow::Result<Self> {
+    let nums = parse::byte_lines(input.as_bytes())
+      .enumerate()
+      .map(|(index, line)| parse::byte_integers(index, line))
+      .try_collect()?;
+
+    Ok(Self { nums })
+  }
+
+  fn configure(&mut self, args: &Args) -> anyhow::Result<()> {
+    let Some(offset) = args.offset else {
+      return Ok(());
+    };
+
+    let mut sum = BigInt::ZERO;
+
+    for (i, (polynomial, prediction)) in self
+      .polynomials()?
+      .iter()
+      .zip(self.predict(offset)?)
+      .enumerate()
+    {
+      println!(
+        "History {} (degree {}) at offset {offset}: {prediction}",
+        i + 1,
+        polynomial.degree()
+      );
+
+      sum += prediction;
+    }
+
+    println!("Sum at offset {offset}: {sum}");
+
+    Ok(())
+  }
+
+  fn part1(&mut self) -> anyhow::Result<String> {
+    self.sum_extrapolated(|polynomial| polynomial.len as i128)
+  }
+
+  fn part2(&mut self) -> anyhow::Result<String> {
+    self.sum_extrapolated(|_| -1)
+  }
+}
+
+impl Day9 {
+  /// Fits a polynomial to every history, failing on the first one that isn't polynomial
+  fn polynomials(&self) -> anyhow::Result<Vec<Polynomial>> {
+    self
+      .nums
+      .iter()
+      .enumerate()
+      .map(|(i, nums)| {
+        Polynomial::fit(nums).map_err(|err| anyhow::anyhow!("History on line {}: {err}", i + 1))
+      })
+      .collect()
+  }
+
+  /// Predicts the value of every history at `offset` steps from its first value,
+  /// so 0 is the first value, the length of the history the next one and -1 the one before
+  pub fn predict(&self, offset: i128) -> anyhow::Result<Vec<BigInt>> {
+    Ok(
+      self
+        .polynomials()?
+        .iter()
+        .map(|polynomial| polynomial.at(&BigInt::from(offset)))
+        .collect_vec(),
+    )
+  }
+
+  /// Sum of every history at the offset `offset` picks for it, see [Day9::predict]
+  fn sum_extrapolated(&self, offset: impl Fn(&Polynomial) -> i128) -> anyhow::Result<String> {
+    Ok(
+      self
+        .polynomials()?
+        .iter()
+        .map(|polynomial| polynomial.at(&BigInt::from(offset(polynomial))))
+        .sum::<BigInt>()
+        .to_string(),
+    )
+  }
+}
+
+/// The leading value of every row in a history's difference table, which is all
+/// that's needed to evaluate it anywhere using Newton's forward difference formula
+#[derive(Debug, Clone)]
+struct Polynomial {
+  differences: Vec<i128>,
+  len: usize,
+}
+
+impl Polynomial {
+  /// Builds the difference table of `nums`, errors if it never reaches a row of zeroes,
+  /// since then the history isn't a polynomial within its length.
+  fn fit(nums: &[i64]) -> anyhow::Result<Self> {
+    let mut current = nums.iter().map(|&n| n as i128).collect_vec();
+    let mut differences = vec![];
+
+    loop {
+      if current.is_empty() {
+        return Err(anyhow::anyhow!(
+          "Difference table of {} values never reaches zero",
+          nums.len()
+        ));
+      }
+
+      if current.iter().all(|n| *n == 0) {
+        break;
+      }
+
+      differences.push(current[0]);
+
+      current = current
+        .iter()
+        .tuple_windows()
+        .map(|(a, b)| b.checked_sub(*a))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| anyhow::Error::msg("Difference table overflowed"))?;
+    }
+
+    Ok(Self {
+      differences,
+      len: nums.len(),
+    })
+  }
+
+  /// Degree of the polynomial, a history of all zeroes has degree 0
+  fn degree(&self) -> usize {
+    self.differences.len().saturating_sub(1)
+  }
+
+  /// Value at `x`, where 0 is the first value of the history. Values far away from the
+  /// history grow like `x^degree`, so they are computed without any bound.
+  fn at(&self, x: &BigInt) -> BigInt {
+    let mut sum = BigInt::ZERO;
+    // Generalized binomial coefficient (x choose j), which also works for negative x
+    let mut binomial = BigInt::from(1);
+
+    for (j, &difference) in self.differences.iter().enumerate() {
+      if j > 0 {
+        // (x choose j) = (x choose j - 1) * (x - j + 1) / j, the division is always exact
+        binomial = binomial * (x - j + 1) / j;
+      }
+
+      sum += &binomial * difference;
+    }
+
+    sum
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn example() {
+    let mut day = Day9::setup(EXAMPLE).unwrap();
+    assert_eq!(day.part1().unwrap(), "114");
+    assert_eq!(day.part2().unwrap(), "2");
+  }
+
+  #[test]
+  fn not_a_polynomial() {
+    let err = Polynomial::fit(&[1, 2, 4, 8, 16]).unwrap_err().to_string();
+    assert!(err.contains("never reaches zero"), "{err}");
+  }
+
+  #[test]
+  fn degrees() {
+    let degree = |nums: &[i64]| Polynomial::fit(nums).unwrap().degree();
+
+    assert_eq!(degree(&[0, 0, 0]), 0);
+    assert_eq!(degree(&[7, 7, 7]), 0);
+    assert_eq!(degree(&[0, 3, 6, 9, 12, 15]), 1);
+    assert_eq!(degree(&[1, 3, 6, 10, 15, 21]), 2);
+    assert_eq!(degree(&[10, 13, 16, 21, 30, 45]), 3);
+  }
+
+  #[test]
+  fn exact_values() {
+    let squares = Polynomial::fit(&[0, 1, 4, 9, 16]).unwrap();
+
+    assert_eq!(squares.at(&BigInt::from(2)), BigInt::from(4));
+    assert_eq!(squares.at(&BigInt::from(-3)), BigInt::from(9));
+    let x = BigInt::from(10).pow(30);
+    assert_eq!(squares.at(&x), x.pow(2));
+  }
+
+  #[test]
+  fn far_offsets() {
+    let day = Day9::setup(EXAMPLE).unwrap();
+    let values = |offset| {
+      day
+        .predict(offset)
+        .unwrap()
+        .iter()
+        .map(|n| n.to_string())
+        .collect_vec()
+    };
+
+    assert_eq!(
+      values(1_000_000),
+      ["3000000", "500001500001", "333332333337000010"]
+    );
+    assert_eq!(values(-500), ["-1500", "124251", "-41918490"]);
+    assert_eq!(values(0), ["0", "1", "10"]);
+  }
+}
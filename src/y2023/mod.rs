@@ -0,0 +1,50 @@
+//! Advent of Code 2023
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+use crate::{run_day, Solver};
+use day1::Day1;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use day13::Day13;
+use day2::Day2;
+use day3::Day3;
+use day4::Day4;
+use day5::Day5;
+use day6::Day6;
+use day7::Day7;
+use day8::Day8;
+use day9::Day9;
+
+/// The solver for a day, or [None] if it hasn't been implemented yet
+pub fn solver(day: u32) -> Option<Solver> {
+  match day {
+    1 => Some(|input, args| run_day::<Day1>(input, args)),
+    2 => Some(|input, args| run_day::<Day2>(input, args)),
+    3 => Some(|input, args| run_day::<Day3>(input, args)),
+    4 => Some(|input, args| run_day::<Day4>(input, args)),
+    5 => Some(|input, args| run_day::<Day5>(input, args)),
+    6 => Some(|input, args| run_day::<Day6>(input, args)),
+    7 => Some(|input, args| run_day::<Day7>(input, args)),
+    8 => Some(|input, args| run_day::<Day8>(input, args)),
+    9 => Some(|input, args| run_day::<Day9>(input, args)),
+    10 => Some(|input, args| run_day::<Day10>(input, args)),
+    11 => Some(|input, args| run_day::<Day11>(input, args)),
+    12 => Some(|input, args| run_day::<Day12>(input, args)),
+    13 => Some(|input, args| run_day::<Day13>(input, args)),
+    _ => None,
+  }
+}